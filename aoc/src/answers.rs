@@ -0,0 +1,120 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use crate::runner::Part;
+
+/// Known answers for the real inputs, read from a manifest like
+/// `answers/2021.toml`.
+///
+/// The manifest uses a small subset of TOML: one `[dayN]` table per day with
+/// `part1` and `part2` keys, holding integers or double-quoted strings.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, Part), String>,
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers, String> {
+        let path = path.as_ref();
+        let manifest = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+        Answers::parse(&manifest).map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    pub fn parse(manifest: &str) -> Result<Answers, String> {
+        let mut answers = BTreeMap::new();
+        let mut day = None;
+
+        for (i, line) in manifest.lines().enumerate() {
+            let error = |reason: &str| format!("line {}: {}", i + 1, reason);
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[') {
+                let number = table
+                    .strip_suffix(']')
+                    .and_then(|table| table.strip_prefix("day"))
+                    .and_then(|number| number.parse::<u32>().ok())
+                    .ok_or_else(|| error("expected a `[dayN]` table"))?;
+                day = Some(number);
+                continue;
+            }
+
+            let day = day.ok_or_else(|| error("expected a `[dayN]` table first"))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `partN = answer`"))?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(error("expected `part1` or `part2`")),
+            };
+            let value = value.trim();
+            let answer = match value.strip_prefix('"') {
+                Some(quoted) => quoted
+                    .strip_suffix('"')
+                    .ok_or_else(|| error("unterminated string"))?,
+                None if value.parse::<i128>().is_ok() => value,
+                None => return Err(error("expected an integer or a string")),
+            };
+            if answers.insert((day, part), answer.to_string()).is_some() {
+                return Err(error("duplicate answer"));
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::Answers;
+    use crate::runner::Part;
+
+    static MANIFEST: &str = r#"# comment
+
+[day1]
+part1 = 7
+part2 = 5
+
+[day13]
+part2 = "EBLUBRFH"
+
+[day25]
+"#;
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse(MANIFEST).unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("7"));
+        assert_eq!(answers.get(1, Part::Two), Some("5"));
+        assert_eq!(answers.get(13, Part::One), None);
+        assert_eq!(answers.get(13, Part::Two), Some("EBLUBRFH"));
+        assert_eq!(answers.get(25, Part::One), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Answers::parse("part1 = 5"),
+            Err("line 1: expected a `[dayN]` table first".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[day1]\npart3 = 5"),
+            Err("line 2: expected `part1` or `part2`".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[day1]\npart1 = five"),
+            Err("line 2: expected an integer or a string".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[day1]\npart1 = 5\npart1 = 6"),
+            Err("line 3: duplicate answer".to_string())
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use std::{fmt, str::FromStr, time::Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::runner::{Day, Part};
+
+/// A timed step of a day: its generator or one of its parts.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Generator,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Generator, Stage::Part1, Stage::Part2];
+}
+
+impl From<Part> for Stage {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Stage::Part1,
+            Part::Two => Stage::Part2,
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "generator" => Ok(Stage::Generator),
+            "part1" => Ok(Stage::Part1),
+            "part2" => Ok(Stage::Part2),
+            _ => Err(format!("invalid stage `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Generator => write!(f, "generator"),
+            Stage::Part1 => write!(f, "part1"),
+            Stage::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+/// Timings of one stage over repeated runs, in nanoseconds.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u32,
+    pub stage: Stage,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Measurement {
+    fn new(day: u32, stage: Stage, mut samples: Vec<u64>) -> Self {
+        samples.sort_unstable();
+        let mid = samples.len() / 2;
+        let median_ns = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Measurement {
+            day,
+            stage,
+            runs: samples.len(),
+            min_ns: samples[0],
+            median_ns,
+            max_ns: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Times the generator and each requested part of `day` separately, `runs`
+/// times each. The parts all reuse the output of a single generator run.
+pub fn measure(
+    day: &Day,
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Vec<Measurement>, String> {
+    assert!(runs > 0, "cannot benchmark zero runs");
+
+    let mut samples = vec![];
+    let mut parsed = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        let result = day.generate(input)?;
+        samples.push(start.elapsed().as_nanos() as u64);
+        parsed = Some(result);
+    }
+    let parsed = parsed.unwrap();
+    let mut measurements = vec![Measurement::new(day.day, Stage::Generator, samples)];
+
+    for &part in parts {
+        let mut samples = vec![];
+        for _ in 0..runs {
+            let start = Instant::now();
+            day.solve(parsed.as_ref(), part)
+                .map_err(|err| format!("{}: {}", part, err))?;
+            samples.push(start.elapsed().as_nanos() as u64);
+        }
+        measurements.push(Measurement::new(day.day, part.into(), samples));
+    }
+
+    Ok(measurements)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("invalid format `{}`, expected csv or json", s)),
+        }
+    }
+}
+
+const CSV_HEADER: &str = "day,stage,runs,min_ns,median_ns,max_ns";
+
+pub fn write(measurements: &[Measurement], format: Format) -> String {
+    match format {
+        Format::Csv => {
+            let mut csv = CSV_HEADER.to_string();
+            for m in measurements {
+                csv += &format!(
+                    "\n{},{},{},{},{},{}",
+                    m.day, m.stage, m.runs, m.min_ns, m.median_ns, m.max_ns
+                );
+            }
+            csv
+        }
+        Format::Json => serde_json::to_string_pretty(measurements).unwrap(),
+    }
+}
+
+/// Reads measurements written by [`write`] in either format.
+pub fn read(saved: &str) -> Result<Vec<Measurement>, String> {
+    if saved.trim_start().starts_with('[') {
+        return serde_json::from_str(saved).map_err(|err| err.to_string());
+    }
+
+    let mut lines = saved.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header.trim() == CSV_HEADER => {}
+        _ => return Err(format!("expected the CSV header `{}`", CSV_HEADER)),
+    }
+    lines
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let error = || format!("line {}: expected {}", i + 1, CSV_HEADER);
+            let fields = line.trim().split(',').collect::<Vec<_>>();
+            if fields.len() != 6 {
+                return Err(error());
+            }
+            let number = |field: &str| field.parse::<u64>().map_err(|_| error());
+            Ok(Measurement {
+                day: fields[0].parse().map_err(|_| error())?,
+                stage: fields[1].parse()?,
+                runs: fields[2].parse().map_err(|_| error())?,
+                min_ns: number(fields[3])?,
+                median_ns: number(fields[4])?,
+                max_ns: number(fields[5])?,
+            })
+        })
+        .collect()
+}
+
+/// A stage whose median time grew by more than the allowed threshold.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Regression {
+    pub day: u32,
+    pub stage: Stage,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let change = (self.current_ns as f64 / self.baseline_ns as f64 - 1.0) * 100.0;
+        write!(
+            f,
+            "day{} {}: median {}ns -> {}ns (+{:.1}%)",
+            self.day, self.stage, self.baseline_ns, self.current_ns, change
+        )
+    }
+}
+
+/// Compares median times with a baseline, flagging every stage that got
+/// slower by more than `threshold` percent. Stages missing from the
+/// baseline are skipped.
+pub fn compare(
+    current: &[Measurement],
+    baseline: &[Measurement],
+    threshold: f64,
+) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|m| {
+            let base = baseline
+                .iter()
+                .find(|b| b.day == m.day && b.stage == m.stage)?;
+            let limit = base.median_ns as f64 * (1.0 + threshold / 100.0);
+            (m.median_ns as f64 > limit).then_some(Regression {
+                day: m.day,
+                stage: m.stage,
+                baseline_ns: base.median_ns,
+                current_ns: m.median_ns,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{compare, measure, read, write, Format, Measurement, Stage};
+    use crate::runner::{Day, Part};
+
+    fn measurement(day: u32, stage: Stage, median_ns: u64) -> Measurement {
+        Measurement {
+            day,
+            stage,
+            runs: 3,
+            min_ns: median_ns / 2,
+            median_ns,
+            max_ns: median_ns * 2,
+        }
+    }
+
+    #[test]
+    fn statistics() {
+        let m = Measurement::new(1, Stage::Part1, vec![30, 10, 20, 50]);
+        assert_eq!((m.runs, m.min_ns, m.median_ns, m.max_ns), (4, 10, 25, 50));
+        let m = Measurement::new(1, Stage::Part1, vec![30, 10, 20]);
+        assert_eq!((m.runs, m.min_ns, m.median_ns, m.max_ns), (3, 10, 20, 30));
+    }
+
+    #[test]
+    fn measure_day() {
+        let day = Day {
+            day: 1,
+            parse: |input| Ok(Box::new(input.len())),
+            part1: |input| Ok((*input.downcast_ref::<usize>().unwrap()).into()),
+            part2: |_| panic!("unsolved"),
+            render: |_, _| None,
+            report: |_| None,
+            examples: Vec::new,
+        };
+        let measurements = measure(&day, "1\n2\n3", &[Part::One], 3).unwrap();
+        let stages = measurements.iter().map(|m| m.stage).collect::<Vec<_>>();
+        assert_eq!(stages, [Stage::Generator, Stage::Part1]);
+        assert!(measurements.iter().all(|m| m.day == 1 && m.runs == 3));
+        assert!(measure(&day, "1", &Part::ALL, 3).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let measurements = vec![
+            measurement(1, Stage::Generator, 100),
+            measurement(1, Stage::Part2, 2000),
+        ];
+        for format in [Format::Csv, Format::Json] {
+            assert_eq!(
+                read(&write(&measurements, format)),
+                Ok(measurements.clone())
+            );
+        }
+        assert_eq!(
+            write(&measurements[..1], Format::Csv),
+            "day,stage,runs,min_ns,median_ns,max_ns\n1,generator,3,50,100,200"
+        );
+        assert!(read("day,stage\n1,generator").is_err());
+    }
+
+    #[test]
+    fn regressions() {
+        let baseline = vec![
+            measurement(1, Stage::Part1, 1000),
+            measurement(1, Stage::Part2, 1000),
+        ];
+        let current = vec![
+            measurement(1, Stage::Part1, 1050),
+            measurement(1, Stage::Part2, 1200),
+            measurement(2, Stage::Part1, 5000),
+        ];
+        let regressions = compare(&current, &baseline, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(
+            regressions[0].to_string(),
+            "day1 part2: median 1000ns -> 1200ns (+20.0%)"
+        );
+    }
+}
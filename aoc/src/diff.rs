@@ -0,0 +1,162 @@
+//! Differential testing: runs a simple reference implementation and an
+//! optimised one on many random inputs and reports the smallest input on
+//! which they disagree.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::rng::Rng;
+
+/// What an implementation did with an input: its result, or the message it
+/// panicked with.
+pub type Outcome<R> = Result<R, String>;
+
+/// The most times [`check`] replaces a disagreeing input by a smaller one.
+pub const MAX_SHRINKS: usize = 10_000;
+
+/// An input on which the two implementations disagree. `seed` is the case
+/// the original input came from, `input` the shrunk version of it.
+#[derive(Debug, PartialEq)]
+pub struct Mismatch<T, R> {
+    pub seed: u64,
+    pub input: T,
+    pub expected: Outcome<R>,
+    pub found: Outcome<R>,
+}
+
+/// Generates an input for each seed in `0..cases` and compares `reference`
+/// with `candidate` on it. On the first disagreement the input is shrunk:
+/// as long as one of the smaller inputs from `shrink` still disagrees, that
+/// one is tried next. Panics count as outcomes, so a panic on one side only
+/// is a disagreement as well.
+///
+/// `shrink` must only return inputs strictly smaller than the one it is
+/// given. In case it does not, shrinking stops after [`MAX_SHRINKS`] steps
+/// rather than going round in circles forever.
+pub fn check<T, R: PartialEq>(
+    cases: u64,
+    generate: impl Fn(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    reference: impl Fn(&T) -> R,
+    candidate: impl Fn(&T) -> R,
+) -> Result<(), Box<Mismatch<T, R>>> {
+    let compare = |input: &T| {
+        let expected = outcome(|| reference(input));
+        let found = outcome(|| candidate(input));
+        (expected != found).then_some((expected, found))
+    };
+
+    for seed in 0..cases {
+        let mut input = generate(&mut Rng::new(seed));
+        let Some(mut outcomes) = compare(&input) else {
+            continue;
+        };
+        'shrink: for _ in 0..MAX_SHRINKS {
+            for smaller in shrink(&input) {
+                if let Some(found) = compare(&smaller) {
+                    (input, outcomes) = (smaller, found);
+                    continue 'shrink;
+                }
+            }
+            break;
+        }
+        let (expected, found) = outcomes;
+        return Err(Box::new(Mismatch {
+            seed,
+            input,
+            expected,
+            found,
+        }));
+    }
+    Ok(())
+}
+
+fn outcome<R>(run: impl FnOnce() -> R) -> Outcome<R> {
+    panic::catch_unwind(AssertUnwindSafe(run)).map_err(|err| {
+        err.downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| err.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panicked".to_string())
+    })
+}
+
+/// Smaller versions of `items`: with chunks of decreasing size left out,
+/// down to single items, then with single items replaced by their own
+/// smaller versions from `shrink_item`.
+pub fn shrink_vec<T: Clone>(items: &[T], shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut smaller = Vec::new();
+    let mut chunk = items.len();
+    while chunk > 0 {
+        for start in (0..items.len()).step_by(chunk) {
+            let end = (start + chunk).min(items.len());
+            smaller.push([&items[..start], &items[end..]].concat());
+        }
+        chunk /= 2;
+    }
+    for (i, item) in items.iter().enumerate() {
+        for replacement in shrink_item(item) {
+            let mut items = items.to_vec();
+            items[i] = replacement;
+            smaller.push(items);
+        }
+    }
+    smaller
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{check, shrink_vec, Mismatch, MAX_SHRINKS};
+
+    fn numbers(rng: &mut crate::rng::Rng) -> Vec<i64> {
+        (0..rng.range(0..=20)).map(|_| rng.range(0..=20)).collect()
+    }
+
+    fn shrink(numbers: &[i64]) -> Vec<Vec<i64>> {
+        shrink_vec(numbers, |&n| {
+            (n > 0).then_some(vec![0, n / 2, n - 1]).unwrap_or_default()
+        })
+    }
+
+    #[test]
+    fn agree() {
+        let sum = |numbers: &Vec<i64>| numbers.iter().sum::<i64>();
+        let backwards = |numbers: &Vec<i64>| numbers.iter().rev().sum::<i64>();
+        assert_eq!(check(100, numbers, |n| shrink(n), sum, backwards), Ok(()));
+    }
+
+    #[test]
+    fn shrinks_to_minimal_input() {
+        let sum = |numbers: &Vec<i64>| numbers.iter().sum::<i64>();
+        let skips_large = |numbers: &Vec<i64>| numbers.iter().filter(|&&n| n < 15).sum::<i64>();
+        let mismatch = check(100, numbers, |n| shrink(n), sum, skips_large).unwrap_err();
+        assert_eq!(mismatch.input, vec![15]);
+        assert_eq!(mismatch.expected, Ok(15));
+        assert_eq!(mismatch.found, Ok(0));
+    }
+
+    #[test]
+    fn panics_disagree() {
+        let first = |numbers: &Vec<i64>| numbers.first().copied().unwrap_or(0);
+        let index = |numbers: &Vec<i64>| numbers[0];
+        let mismatch = check(100, numbers, |n| shrink(n), first, index).unwrap_err();
+        assert_eq!(
+            *mismatch,
+            Mismatch {
+                seed: mismatch.seed,
+                input: vec![],
+                expected: Ok(0),
+                found: Err("index out of bounds: the len is 0 but the index is 0".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn shrinking_stops() {
+        // A shrink that hands back the same input would otherwise loop forever.
+        let mismatch = check(1, |_| 7, |&n| vec![n], |&n| n, |&n| n + 1).unwrap_err();
+        assert_eq!(mismatch.input, 7);
+
+        let growing = |&n: &usize| vec![n + 1];
+        let mismatch = check(1, |_| 0, growing, |&n| n, |&n| n + 1).unwrap_err();
+        assert_eq!(mismatch.input, MAX_SHRINKS);
+    }
+}
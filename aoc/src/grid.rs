@@ -0,0 +1,296 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::parse::{lines, Line, ParseError};
+
+/// A `(row, column)` position in a grid.
+pub type Pos = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// What happens when stepping over the edge of a grid.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Topology {
+    /// There is nothing beyond the edges.
+    Bounded,
+    /// Leaving on one side enters again on the opposite side.
+    Torus,
+}
+
+/// A rectangular grid of cells, stored row by row in a single `Vec`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    topology: Topology,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "cells do not fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+            topology: Topology::Bounded,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    /// Parses one row per line of `input`, rejecting rows that are shorter
+    /// or longer than the first one.
+    pub fn parse(
+        day: u32,
+        input: &str,
+        mut parse_row: impl FnMut(&Line) -> Result<Vec<T>, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in lines(day, input) {
+            let row = parse_row(&line)?;
+            let width = *width.get_or_insert(row.len());
+            if row.len() != width {
+                return Err(line.error(line.text, format!("expected {} cells", width)));
+            }
+            cells.extend(row);
+            height += 1;
+        }
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        if row < self.height && col < self.width {
+            Some(&mut self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.len()).map(move |i| (i / width, i % width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+            topology: self.topology,
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// The position `delta` away from `pos`, wrapping around on a torus.
+    pub fn offset(&self, (row, col): Pos, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        let step = |from: usize, delta: isize, size: usize| match self.topology {
+            Topology::Bounded => from.checked_add_signed(delta).filter(|&to| to < size),
+            Topology::Torus if size > 0 => {
+                Some((from as isize + delta).rem_euclid(size as isize) as usize)
+            }
+            Topology::Torus => None,
+        };
+        Some((
+            step(row, d_row, self.height)?,
+            step(col, d_col, self.width)?,
+        ))
+    }
+
+    /// The up to four positions directly above, left, right and below `pos`.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// The up to eight positions around `pos`, diagonals included.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_AROUND
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a grid with one digit in the given radix per cell.
+    pub fn parse_digits(day: u32, input: &str, radix: u32) -> Result<Self, ParseError> {
+        Grid::parse(day, input, |line| line.digits(radix))
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(day: u32, input: &str) -> Result<Self, ParseError> {
+        Grid::parse(day, input, |line| Ok(line.text.chars().collect()))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {:?} out of bounds", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {:?} out of bounds", pos))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use itertools::Itertools;
+
+    use super::{Grid, Topology};
+    use crate::parse::ParseError;
+
+    static INPUT: &str = "123\n456";
+
+    #[test]
+    fn parse() {
+        let grid = Grid::parse_digits(0, INPUT, 10).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), INPUT);
+
+        let chars = Grid::parse_chars(0, "ab\ncd").unwrap();
+        assert_eq!(chars, Grid::new(2, 2, vec!['a', 'b', 'c', 'd']));
+    }
+
+    #[test]
+    fn parse_ragged() {
+        assert_eq!(
+            Grid::parse_digits(9, "123\n45", 10),
+            Err(ParseError::new(9, 2, 1, "45", "expected 3 cells"))
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::parse_digits(0, INPUT, 10).unwrap();
+        assert_eq!(grid.rows().collect_vec(), vec![&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.copied().collect_vec())
+                .collect_vec(),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        assert_eq!(grid.positions().nth(4), Some((1, 1)));
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::from_fn(3, 3, |(row, col)| row * 3 + col);
+        assert_eq!(grid.neighbors4((0, 0)).collect_vec(), vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbors8((0, 2)).collect_vec(),
+            vec![(0, 1), (1, 1), (1, 2)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn torus() {
+        let grid = Grid::from_fn(4, 3, |pos| pos).with_topology(Topology::Torus);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect_vec(),
+            vec![(2, 0), (0, 3), (0, 1), (1, 0)]
+        );
+        assert_eq!(grid.offset((2, 3), (1, 1)), Some((0, 0)));
+        assert_eq!(grid.neighbors8((2, 3)).count(), 8);
+    }
+}
@@ -0,0 +1,15 @@
+//! Helpers shared by the solutions of every year: input parsing, grids, the
+//! `Solution` trait and the type-erased day registry used by the runner,
+//! running days in parallel, benchmarking, rendering and differential testing.
+
+pub mod answers;
+pub mod bench;
+pub mod diff;
+pub mod grid;
+pub mod ocr;
+pub mod parallel;
+pub mod parse;
+pub mod render;
+pub mod rng;
+pub mod runner;
+pub mod solution;
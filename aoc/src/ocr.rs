@@ -0,0 +1,79 @@
+//! Reads the block letters some puzzles draw as their answer.
+
+use crate::grid::Grid;
+
+const HEIGHT: usize = 6;
+const WIDTH: usize = 4;
+
+/// The letters known so far, each drawn 4 cells wide and 6 cells high.
+const LETTERS: [(char, &str); 16] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// Reads letters that are 4 cells wide and 6 cells high, with one empty
+/// column between them. Returns `None` if any letter is unknown.
+pub fn read(grid: &Grid<bool>) -> Option<String> {
+    if grid.height() != HEIGHT {
+        return None;
+    }
+
+    (0..grid.width())
+        .step_by(WIDTH + 1)
+        .map(|left| {
+            let glyph = (0..HEIGHT)
+                .flat_map(|row| (left..left + WIDTH).map(move |col| (row, col)))
+                .map(|pos| match grid.get(pos) {
+                    Some(true) => '#',
+                    _ => '.',
+                })
+                .collect::<String>();
+            LETTERS
+                .iter()
+                .find(|(_, drawn)| *drawn == glyph)
+                .map(|&(letter, _)| letter)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::read;
+    use crate::grid::Grid;
+
+    fn grid(drawing: &str) -> Grid<bool> {
+        Grid::parse_chars(0, drawing).unwrap().map(|&c| c == '#')
+    }
+
+    #[test]
+    fn letters() {
+        let drawing = "\
+#..#.###.
+#..#.#..#
+#..#.#..#
+#..#.###.
+#..#.#.#.
+.##..#..#";
+        assert_eq!(read(&grid(drawing)), Some("UR".to_string()));
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(read(&grid("####\n#..#\n####")), None);
+        assert_eq!(read(&grid("#..#\n".repeat(6).trim_end())), None);
+    }
+}
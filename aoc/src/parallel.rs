@@ -0,0 +1,384 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError, Sender},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    runner::{Day, Part},
+    solution::Answer,
+};
+
+/// How one part of one day ended.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Status {
+    Solved(Answer),
+    /// The input could not be read or parsed, or the solution panicked.
+    Failed(String),
+    /// The day did not finish within the time limit.
+    TimedOut,
+}
+
+/// One row of the summary: a part of a day, how it ended and how long it
+/// took, including the generator. Parts that timed out or had no input have
+/// no time.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Outcome {
+    pub day: u32,
+    pub part: Part,
+    pub status: Status,
+    pub time: Option<Duration>,
+}
+
+/// A day to run along with its input, or the reason there is none.
+pub struct Task {
+    pub day: Day,
+    pub input: Result<String, String>,
+}
+
+enum Message {
+    Started(usize),
+    Finished(usize, Vec<Outcome>),
+}
+
+/// Runs every task on a pool of `threads` threads and returns the outcomes in
+/// the order of the tasks.
+///
+/// Panics are caught per part. A day that is still running after `timeout`
+/// is given up on: its parts are reported as timed out and a fresh thread
+/// takes its place, since there is no way to stop the stuck one.
+pub fn run_all(
+    tasks: Vec<Task>,
+    parts: &[Part],
+    threads: usize,
+    timeout: Duration,
+) -> Vec<Outcome> {
+    assert!(threads > 0, "cannot run on zero threads");
+
+    let tasks = Arc::new(tasks);
+    let next = Arc::new(AtomicUsize::new(0));
+    let (sender, receiver) = mpsc::channel();
+    let spawn = || {
+        let (tasks, next, sender) = (tasks.clone(), next.clone(), sender.clone());
+        let parts = parts.to_vec();
+        thread::spawn(move || work(&tasks, &next, &parts, &sender));
+    };
+    for _ in 0..threads.min(tasks.len()) {
+        spawn();
+    }
+
+    let mut results = vec![None; tasks.len()];
+    let mut running = HashMap::new();
+    let mut remaining = tasks.len();
+    while remaining > 0 {
+        let deadline = running.values().min().copied();
+        let message = match deadline {
+            Some(deadline) => receiver.recv_timeout(deadline - Instant::now().min(deadline)),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match message {
+            Ok(Message::Started(i)) => {
+                running.insert(i, Instant::now() + timeout);
+            }
+            Ok(Message::Finished(i, outcomes)) => {
+                if running.remove(&i).is_some() {
+                    results[i] = Some(outcomes);
+                    remaining -= 1;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                let expired = running
+                    .iter()
+                    .filter(|&(_, &deadline)| deadline <= now)
+                    .map(|(&i, _)| i)
+                    .collect::<Vec<_>>();
+                for i in expired {
+                    running.remove(&i);
+                    results[i] = Some(timed_out(tasks[i].day.day, parts));
+                    remaining -= 1;
+                    spawn();
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => unreachable!("the pool keeps a sender"),
+        }
+    }
+
+    results.into_iter().flatten().flatten().collect()
+}
+
+fn work(tasks: &[Task], next: &AtomicUsize, parts: &[Part], sender: &Sender<Message>) {
+    loop {
+        let i = next.fetch_add(1, Ordering::SeqCst);
+        let Some(task) = tasks.get(i) else {
+            return;
+        };
+        // Once the pool has returned nobody is listening any more, and a
+        // thread that was given up on has nothing left to do.
+        if sender.send(Message::Started(i)).is_err() {
+            return;
+        }
+        let outcomes = run(task, parts);
+        if sender.send(Message::Finished(i, outcomes)).is_err() {
+            return;
+        }
+    }
+}
+
+fn run(task: &Task, parts: &[Part]) -> Vec<Outcome> {
+    let day = task.day.day;
+    let start = Instant::now();
+    let parsed = task
+        .input
+        .clone()
+        .and_then(|input| task.day.generate(&input));
+    let generated = start.elapsed();
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let status = match &parsed {
+                Ok(parsed) => match task.day.solve(parsed.as_ref(), part) {
+                    Ok(answer) => Status::Solved(answer),
+                    Err(err) => Status::Failed(err),
+                },
+                Err(err) => Status::Failed(err.clone()),
+            };
+            Outcome {
+                day,
+                part,
+                status,
+                time: task.input.is_ok().then(|| generated + start.elapsed()),
+            }
+        })
+        .collect()
+}
+
+fn timed_out(day: u32, parts: &[Part]) -> Vec<Outcome> {
+    parts
+        .iter()
+        .map(|&part| Outcome {
+            day,
+            part,
+            status: Status::TimedOut,
+            time: None,
+        })
+        .collect()
+}
+
+/// The outcomes as a table with one row per part, answers and times aligned
+/// in columns.
+pub struct Summary<'a>(pub &'a [Outcome]);
+
+impl fmt::Display for Summary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows = self
+            .0
+            .iter()
+            .map(|outcome| {
+                let (answer, status) = match &outcome.status {
+                    Status::Solved(answer) => (answer.to_string(), "ok".to_string()),
+                    Status::Failed(err) => ("-".to_string(), format!("failed: {}", err)),
+                    Status::TimedOut => ("-".to_string(), "timed out".to_string()),
+                };
+                let time = match outcome.time {
+                    Some(time) => format!("{:.1?}", time),
+                    None => "-".to_string(),
+                };
+                [
+                    outcome.day.to_string(),
+                    outcome.part.to_string(),
+                    answer.replace('\n', " "),
+                    time,
+                    status,
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let header = ["day", "part", "answer", "time", "status"].map(String::from);
+        let mut widths = header.clone().map(|column| column.len());
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for row in std::iter::once(&header).chain(&rows) {
+            let line = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::{
+        collections::BTreeMap,
+        sync::{
+            mpsc::{self, Receiver, Sender},
+            Mutex,
+        },
+        time::Duration,
+    };
+
+    use super::{run_all, Outcome, Status, Summary, Task};
+    use crate::{
+        runner::{self, Day, Part},
+        solution::Answer,
+    };
+
+    /// The channels that tasks made by [`stuck`] wait on, by input.
+    static STUCK: Mutex<BTreeMap<String, Receiver<()>>> = Mutex::new(BTreeMap::new());
+
+    fn day(day: u32) -> Day {
+        Day {
+            day,
+            parse: |input| Ok(Box::new(input.to_string())),
+            part1: |input| {
+                let input = input.downcast_ref::<String>().unwrap();
+                let stuck = STUCK.lock().unwrap().remove(input);
+                if let Some(receiver) = stuck {
+                    // Blocks until the test drops the sender.
+                    let _ = receiver.recv();
+                }
+                Ok(input.len().into())
+            },
+            part2: |input| match input.downcast_ref::<String>().unwrap().as_str() {
+                "" => panic!("empty input"),
+                input => Ok(input.to_uppercase().into()),
+            },
+            render: |_, _| None,
+            report: |_| None,
+            examples: Vec::new,
+        }
+    }
+
+    fn task(n: u32, input: &str) -> Task {
+        Task {
+            day: day(n),
+            input: Ok(input.to_string()),
+        }
+    }
+
+    /// A task whose first part only returns once the sender is dropped, named
+    /// by an input unique across tests.
+    fn stuck(n: u32, input: &str) -> (Task, Sender<()>) {
+        let (sender, receiver) = mpsc::channel();
+        STUCK.lock().unwrap().insert(input.to_string(), receiver);
+        (task(n, input), sender)
+    }
+
+    fn statuses(outcomes: &[Outcome]) -> Vec<(u32, Part, Status)> {
+        outcomes
+            .iter()
+            .map(|outcome| (outcome.day, outcome.part, outcome.status.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn isolation() {
+        let (stuck, _release) = stuck(2, "stuck in isolation");
+        let tasks = vec![
+            task(1, "abc"),
+            stuck,
+            task(3, ""),
+            Task {
+                day: day(4),
+                input: Err("cannot read input".to_string()),
+            },
+            task(5, "de"),
+        ];
+        // Printing the panic of day 3, with a backtrace if `RUST_BACKTRACE` is
+        // set, can take longer than the timeout.
+        runner::quiet_panics();
+        let outcomes = run_all(tasks, &Part::ALL, 2, Duration::from_millis(100));
+        assert_eq!(
+            statuses(&outcomes),
+            vec![
+                (1, Part::One, Status::Solved(Answer::Int(3))),
+                (1, Part::Two, Status::Solved("ABC".into())),
+                (2, Part::One, Status::TimedOut),
+                (2, Part::Two, Status::TimedOut),
+                (3, Part::One, Status::Solved(Answer::Int(0))),
+                (3, Part::Two, Status::Failed("empty input".to_string())),
+                (
+                    4,
+                    Part::One,
+                    Status::Failed("cannot read input".to_string())
+                ),
+                (
+                    4,
+                    Part::Two,
+                    Status::Failed("cannot read input".to_string())
+                ),
+                (5, Part::One, Status::Solved(Answer::Int(2))),
+                (5, Part::Two, Status::Solved("DE".into())),
+            ]
+        );
+        assert_eq!(outcomes[2].time, None);
+        assert_eq!(outcomes[6].time, None);
+        assert!(outcomes[5].time.is_some());
+    }
+
+    #[test]
+    fn more_timeouts_than_threads() {
+        let (first, _release_first) = stuck(1, "stuck first");
+        let (second, _release_second) = stuck(2, "stuck second");
+        let tasks = vec![first, second, task(3, "ok")];
+        let outcomes = run_all(tasks, &[Part::One], 1, Duration::from_millis(100));
+        assert_eq!(
+            statuses(&outcomes),
+            vec![
+                (1, Part::One, Status::TimedOut),
+                (2, Part::One, Status::TimedOut),
+                (3, Part::One, Status::Solved(Answer::Int(2))),
+            ]
+        );
+    }
+
+    #[test]
+    fn summary() {
+        let outcomes = [
+            Outcome {
+                day: 9,
+                part: Part::One,
+                status: Status::Solved(Answer::Int(15)),
+                time: Some(Duration::from_micros(1500)),
+            },
+            Outcome {
+                day: 10,
+                part: Part::Two,
+                status: Status::Failed("no incomplete lines".to_string()),
+                time: Some(Duration::from_micros(20)),
+            },
+            Outcome {
+                day: 17,
+                part: Part::One,
+                status: Status::TimedOut,
+                time: None,
+            },
+        ];
+        assert_eq!(
+            Summary(&outcomes).to_string(),
+            "\
+day  part   answer  time    status
+9    part1  15      1.5ms   ok
+10   part2  -       20.0µs  failed: no incomplete lines
+17   part1  -       -       timed out
+"
+        );
+    }
+}
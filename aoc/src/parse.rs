@@ -0,0 +1,269 @@
+use std::{any::type_name, error::Error, fmt, str::FromStr};
+
+/// An error in a puzzle input, pointing at the offending text.
+///
+/// Lines and columns are 1-based, columns count characters rather than bytes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u32,
+        line: usize,
+        column: usize,
+        text: &str,
+        reason: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            day,
+            line,
+            column,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// An error for an input that ended before `what` could be read.
+    pub fn end_of_input(day: u32, input: &str, what: &str) -> Self {
+        ParseError::new(
+            day,
+            lines(day, input).count() + 1,
+            1,
+            "",
+            format!("expected {}, found end of input", what),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day{} line {}, column {}: {}",
+            self.day, self.line, self.column, self.reason
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " (found `{}`)", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// A single line of a puzzle input, remembering where it came from so that
+/// errors about any part of it can point to the right line and column.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u32,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// An error about `token`, which should be a slice of this line's text.
+    /// Tokens from anywhere else are reported at the start of the line.
+    pub fn error(&self, token: &str, reason: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset + token.len() <= self.text.len())
+            .filter(|&offset| self.text.is_char_boundary(offset))
+            .unwrap_or(0);
+        let column = self.text[..offset].chars().count() + 1;
+        ParseError::new(self.day, self.number, column, token, reason)
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token
+            .parse()
+            .map_err(|_| self.error(token, format!("expected {}", type_name::<T>())))
+    }
+
+    pub fn split_once<'t>(
+        &self,
+        token: &'t str,
+        separator: &str,
+    ) -> Result<(&'t str, &'t str), ParseError> {
+        token
+            .split_once(separator)
+            .ok_or_else(|| self.error(token, format!("expected `{}`", separator)))
+    }
+
+    pub fn strip_prefix<'t>(&self, token: &'t str, prefix: &str) -> Result<&'t str, ParseError> {
+        token
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(token, format!("expected `{}`", prefix)))
+    }
+
+    pub fn strip_suffix<'t>(&self, token: &'t str, suffix: &str) -> Result<&'t str, ParseError> {
+        token
+            .strip_suffix(suffix)
+            .ok_or_else(|| self.error(token, format!("expected `{}`", suffix)))
+    }
+
+    /// The characters of the line, each along with the slice it occupies so
+    /// that errors can point at it.
+    pub fn chars(&self) -> impl Iterator<Item = (char, &'a str)> {
+        let text = self.text;
+        text.char_indices()
+            .map(move |(i, c)| (c, &text[i..i + c.len_utf8()]))
+    }
+
+    /// Parses every character of the line as a digit in the given radix.
+    pub fn digits(&self, radix: u32) -> Result<Vec<u32>, ParseError> {
+        self.chars()
+            .map(|(c, token)| {
+                c.to_digit(radix)
+                    .ok_or_else(|| self.error(token, format!("expected a base {} digit", radix)))
+            })
+            .collect()
+    }
+}
+
+/// The lines of `input`, numbered from 1, after normalising it.
+pub fn lines(day: u32, input: &str) -> impl Iterator<Item = Line<'_>> {
+    normalize(input).enumerate().map(move |(i, text)| Line {
+        day,
+        number: i + 1,
+        text,
+    })
+}
+
+/// Splits `input` into lines, dropping what editors and browsers tend to add
+/// to a downloaded input: a byte order mark, `\r\n` line endings, trailing
+/// whitespace and trailing blank lines. Every generator reads its input
+/// through this, so none of them has to care.
+///
+/// Nothing but the removed text changes, so the lines are still slices of
+/// `input` and columns within them stay the same.
+pub fn normalize(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .strip_prefix('\u{feff}')
+        .unwrap_or(input)
+        .trim_end()
+        .lines()
+        .map(str::trim_end)
+}
+
+/// `input` the way a Windows editor might have saved it: with a byte order
+/// mark, `\r\n` line endings, trailing spaces and a few blank lines at the
+/// end. Meant for checking that generators do not depend on the exact
+/// formatting of their input.
+pub fn mangle(input: &str) -> String {
+    let mut mangled = String::from('\u{feff}');
+    for line in input.lines() {
+        mangled.push_str(line);
+        mangled.push_str(" \t\r\n");
+    }
+    mangled.push_str("\r\n  \r\n");
+    mangled
+}
+
+/// The lines of `input`, grouped into blocks separated by blank lines.
+pub fn blocks(day: u32, input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![vec![]];
+    for line in lines(day, input) {
+        if line.text.is_empty() {
+            blocks.push(vec![]);
+        } else {
+            blocks.last_mut().unwrap().push(line);
+        }
+    }
+    blocks.retain(|block| !block.is_empty());
+    blocks
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{blocks, lines, mangle, normalize, ParseError};
+
+    #[test]
+    fn error_position() {
+        let line = lines(2, "forward 5\ndown x").nth(1).unwrap();
+        let (_, value) = line.split_once(line.text, " ").unwrap();
+        let err = line.parse::<i32>(value).unwrap_err();
+        assert_eq!(err, ParseError::new(2, 2, 6, "x", "expected i32"));
+        assert_eq!(
+            err.to_string(),
+            "day2 line 2, column 6: expected i32 (found `x`)"
+        );
+    }
+
+    #[test]
+    fn error_outside_line() {
+        let line = lines(1, "abc").next().unwrap();
+        let elsewhere = String::from("bc");
+        assert_eq!(line.error(&line.text[1..], "bad").column, 2);
+        assert_eq!(line.error(&elsewhere, "bad").column, 1);
+    }
+
+    #[test]
+    fn affixes() {
+        let line = lines(4, "<abc>").next().unwrap();
+        let inner = line.strip_prefix(line.text, "<").unwrap();
+        assert_eq!(line.strip_suffix(inner, ">"), Ok("abc"));
+        assert_eq!(
+            line.strip_suffix(&inner[..2], ">"),
+            Err(ParseError::new(4, 1, 2, "ab", "expected `>`"))
+        );
+    }
+
+    #[test]
+    fn digits() {
+        let line = lines(3, "0110").next().unwrap();
+        assert_eq!(line.digits(2), Ok(vec![0, 1, 1, 0]));
+
+        let line = lines(3, "0110\n01ä2").nth(1).unwrap();
+        let err = line.digits(10).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "ä"));
+        let err = lines(3, "0112").next().unwrap().digits(2).unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (4, "2"));
+    }
+
+    #[test]
+    fn split_blocks() {
+        let blocks = blocks(4, "a\nb\n\nc\n\n\nd");
+        let numbers = blocks
+            .iter()
+            .map(|b| b.iter().map(|l| l.number).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![vec![1, 2], vec![4], vec![7]]);
+    }
+
+    #[test]
+    fn normalized() {
+        let input = "\u{feff}on x=1..2 \r\n\r\n  off\t\r\n\r\n \n";
+        assert_eq!(
+            normalize(input).collect::<Vec<_>>(),
+            vec!["on x=1..2", "", "  off"]
+        );
+        assert_eq!(
+            normalize(&mangle("a\n\nb")).collect::<Vec<_>>(),
+            vec!["a", "", "b"]
+        );
+        assert_eq!(normalize("\n\n").count(), 0);
+
+        let line = lines(22, input).next().unwrap();
+        let (_, range) = line.split_once(line.text, "x=").unwrap();
+        assert_eq!(line.parse::<i32>(range).unwrap_err().column, 6);
+    }
+
+    #[test]
+    fn end_of_input() {
+        let err = ParseError::end_of_input(13, "1,2\n3,4", "fold instructions");
+        assert_eq!(err.line, 3);
+        let err = ParseError::end_of_input(13, "1,2\r\n3,4\r\n\r\n", "fold instructions");
+        assert_eq!(err.line, 3);
+        assert_eq!(
+            err.to_string(),
+            "day13 line 3, column 1: expected fold instructions, found end of input"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Images of puzzle states, written as PPM or SVG files.
+
+use std::{fmt, fs, io, path::Path};
+
+use crate::grid::Grid;
+
+/// A picture with one colour per grid cell.
+pub type Image = Grid<Color>;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GRAY: Color = Color::rgb(128, 128, 128);
+    pub const RED: Color = Color::rgb(230, 40, 40);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b }
+    }
+
+    /// Shades `value` from dark blue at 0 over red to yellow at `max`.
+    pub fn heat(value: f64, max: f64) -> Self {
+        let t = if max > 0.0 {
+            (value / max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let channel = |x: f64| (x.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Color::rgb(channel(2.0 * t), channel(2.0 * t - 1.0), channel(0.5 - t))
+    }
+
+    /// A distinct colour for every id; consecutive ids get very different
+    /// hues.
+    pub fn id(id: usize) -> Self {
+        const GOLDEN_RATIO: f64 = 0.618_033_988_749_895;
+        Color::hsv((id as f64 * GOLDEN_RATIO).fract(), 0.65, 0.95)
+    }
+
+    fn hsv(hue: f64, saturation: f64, value: f64) -> Self {
+        let sector = hue * 6.0;
+        let f = sector.fract();
+        let (p, q, t) = (
+            value * (1.0 - saturation),
+            value * (1.0 - saturation * f),
+            value * (1.0 - saturation * (1.0 - f)),
+        );
+        let (r, g, b) = match sector as u32 % 6 {
+            0 => (value, t, p),
+            1 => (q, value, p),
+            2 => (p, value, t),
+            3 => (p, q, value),
+            4 => (t, p, value),
+            _ => (value, p, q),
+        };
+        let channel = |x: f64| (x * 255.0).round() as u8;
+        Color::rgb(channel(r), channel(g), channel(b))
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// A binary PPM (`P6`) with `cell` by `cell` pixels per grid cell.
+pub fn ppm(image: &Image, cell: usize) -> Vec<u8> {
+    let mut bytes = format!(
+        "P6\n{} {}\n255\n",
+        image.width() * cell,
+        image.height() * cell
+    )
+    .into_bytes();
+    for row in image.rows() {
+        for _ in 0..cell {
+            for color in row {
+                for _ in 0..cell {
+                    bytes.extend([color.r, color.g, color.b]);
+                }
+            }
+        }
+    }
+    bytes
+}
+
+/// An SVG with a `cell` by `cell` square per grid cell. Runs of the same
+/// colour within a row are drawn as one rectangle.
+pub fn svg(image: &Image, cell: usize) -> String {
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" shape-rendering=\"crispEdges\">\n",
+        w = image.width() * cell,
+        h = image.height() * cell
+    );
+    for (y, row) in image.rows().enumerate() {
+        let mut x = 0;
+        for run in row.chunk_by(|a, b| a == b) {
+            svg += &format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+                x * cell,
+                y * cell,
+                run.len() * cell,
+                cell,
+                run[0]
+            );
+            x += run.len();
+        }
+    }
+    svg + "</svg>\n"
+}
+
+/// Writes `image` as PPM or SVG, depending on the extension of `path`.
+pub fn save(image: &Image, path: impl AsRef<Path>, cell: usize) -> io::Result<()> {
+    let path = path.as_ref();
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("ppm") => fs::write(path, ppm(image, cell)),
+        Some("svg") => fs::write(path, svg(image, cell)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{}: expected a .ppm or .svg file", path.display()),
+        )),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::HashSet;
+
+    use super::{ppm, svg, Color, Image};
+
+    fn image() -> Image {
+        Image::new(3, 1, vec![Color::RED, Color::RED, Color::WHITE])
+    }
+
+    #[test]
+    fn colors() {
+        assert_eq!(Color::RED.to_string(), "#e62828");
+        assert_eq!(Color::heat(0.0, 10.0), Color::rgb(0, 0, 128));
+        assert_eq!(Color::heat(10.0, 10.0), Color::rgb(255, 255, 0));
+        assert_eq!(Color::heat(3.0, 0.0), Color::heat(0.0, 1.0));
+        let ids = (0..20).map(Color::id).collect::<HashSet<_>>();
+        assert_eq!(ids.len(), 20);
+    }
+
+    #[test]
+    fn write_ppm() {
+        let bytes = ppm(&image(), 1);
+        assert_eq!(&bytes[..11], b"P6\n3 1\n255\n");
+        assert_eq!(&bytes[11..], [230, 40, 40, 230, 40, 40, 255, 255, 255]);
+        assert_eq!(ppm(&image(), 2).len(), 11 + 6 * 2 * 3);
+    }
+
+    #[test]
+    fn write_svg() {
+        let svg = svg(&image(), 10);
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains("width=\"30\" height=\"10\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"20\" height=\"10\" fill=\"#e62828\"/>"));
+        assert!(
+            svg.contains("<rect x=\"20\" y=\"0\" width=\"10\" height=\"10\" fill=\"#ffffff\"/>")
+        );
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
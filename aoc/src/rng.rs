@@ -0,0 +1,87 @@
+use std::ops::RangeInclusive;
+
+/// A small deterministic PRNG (SplitMix64).
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A uniform number in `0..n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "cannot pick from an empty range");
+        // Reject the top values that would make the low ones more likely.
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+
+    /// A uniform number in `range`.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "cannot pick from an empty range");
+        let span = end.abs_diff(start);
+        match span.checked_add(1) {
+            Some(n) => start.wrapping_add(self.below(n) as i64),
+            None => self.next_u64() as i64,
+        }
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use itertools::Itertools;
+
+    use super::Rng;
+
+    #[test]
+    fn deterministic() {
+        let mut rng = Rng::new(42);
+        let first = (0..5).map(|_| rng.next_u64()).collect_vec();
+        let mut rng = Rng::new(42);
+        assert_eq!((0..5).map(|_| rng.next_u64()).collect_vec(), first);
+        assert_ne!(Rng::new(43).next_u64(), first[0]);
+
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3..=3)));
+        }
+        assert_eq!(rng.range(7..=7), 7);
+        let mut items = (0..10).collect_vec();
+        rng.shuffle(&mut items);
+        assert_eq!(
+            items.iter().copied().sorted().collect_vec(),
+            (0..10).collect_vec()
+        );
+    }
+}
@@ -0,0 +1,344 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use crate::{
+    parse::ParseError,
+    render::Image,
+    solution::{Answer, Example, Solution},
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part `{}`, expected 1 or 2", s)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part1"),
+            Part::Two => write!(f, "part2"),
+        }
+    }
+}
+
+/// A solved day, with its generator and both parts erased behind `Any` so
+/// that every day fits into the same registry.
+pub struct Day {
+    pub day: u32,
+    pub parse: fn(&str) -> Result<Box<dyn Any>, ParseError>,
+    pub part1: fn(&dyn Any) -> Result<Answer, String>,
+    pub part2: fn(&dyn Any) -> Result<Answer, String>,
+    pub render: fn(&dyn Any, Option<usize>) -> Option<Image>,
+    pub report: fn(&dyn Any) -> Option<serde_json::Value>,
+    pub examples: fn() -> Vec<Example>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            parse: |input| Ok(Box::new(S::parse(input)?)),
+            part1: |input| S::part1(downcast::<S>(input)),
+            part2: |input| S::part2(downcast::<S>(input)),
+            render: |input, step| S::render(downcast::<S>(input), step),
+            report: |input| S::report(downcast::<S>(input)),
+            examples: S::examples,
+        }
+    }
+
+    /// Runs the generator once and then every requested part on its output.
+    /// A generator that rejects the input or panics fails all requested parts.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Vec<(Part, Result<Answer, String>)> {
+        let parsed = match self.generate(input) {
+            Ok(parsed) => parsed,
+            Err(err) => return parts.iter().map(|&part| (part, Err(err.clone()))).collect(),
+        };
+
+        parts
+            .iter()
+            .map(|&part| (part, self.solve(parsed.as_ref(), part)))
+            .collect()
+    }
+
+    /// Runs the generator, catching panics.
+    ///
+    /// The input is passed on as it is: generators read it through
+    /// [`crate::parse::lines`], which normalises line endings and whitespace.
+    pub fn generate(&self, input: &str) -> Result<Box<dyn Any>, String> {
+        match catch(|| (self.parse)(input)) {
+            Ok(Ok(parsed)) => Ok(parsed),
+            Ok(Err(err)) => Err(format!("invalid input: {}", err)),
+            Err(err) => Err(format!("generator failed: {}", err)),
+        }
+    }
+
+    /// Solves one part on the generator's output. Panics are caught and
+    /// reported like the errors a part returns.
+    pub fn solve(&self, parsed: &dyn Any, part: Part) -> Result<Answer, String> {
+        let solve = match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        };
+        catch(|| solve(parsed))?
+    }
+
+    /// Runs every requested part on each of the day's examples.
+    pub fn run_examples(&self, parts: &[Part]) -> Vec<ExampleRun> {
+        (self.examples)()
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, example)| {
+                self.run(example.input, parts)
+                    .into_iter()
+                    .map(move |(part, found)| ExampleRun {
+                        example: i + 1,
+                        part,
+                        expected: match part {
+                            Part::One => example.part1.clone(),
+                            Part::Two => example.part2.clone(),
+                        },
+                        found,
+                    })
+            })
+            .collect()
+    }
+
+    /// Draws the state after `step` steps, or the final state.
+    pub fn image(&self, input: &str, step: Option<usize>) -> Result<Image, String> {
+        let parsed = self.generate(input)?;
+        catch(|| (self.render)(parsed.as_ref(), step))?
+            .ok_or_else(|| format!("day{} has nothing to render", self.day))
+    }
+
+    /// The day's analysis of the input.
+    pub fn analysis(&self, input: &str) -> Result<serde_json::Value, String> {
+        let parsed = self.generate(input)?;
+        catch(|| (self.report)(parsed.as_ref()))?
+            .ok_or_else(|| format!("day{} has nothing to report", self.day))
+    }
+}
+
+/// One part of a day run on one of its examples, numbered from 1.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExampleRun {
+    pub example: usize,
+    pub part: Part,
+    pub expected: Option<Answer>,
+    pub found: Result<Answer, String>,
+}
+
+impl ExampleRun {
+    /// Whether the part succeeded with the expected answer, if there is one.
+    pub fn passed(&self) -> bool {
+        match (&self.found, &self.expected) {
+            (Ok(found), Some(expected)) => found == expected,
+            (Ok(_), None) => true,
+            (Err(_), _) => false,
+        }
+    }
+}
+
+/// One year's solutions. Inputs and answers live in per-year directories
+/// below the workspace root.
+pub struct Year {
+    pub year: u32,
+    pub days: fn() -> Vec<Day>,
+}
+
+impl Year {
+    /// Where the real input for `day` is expected, relative to the workspace
+    /// root.
+    pub fn input_path(&self, day: u32) -> PathBuf {
+        format!("input/{}/day{}.txt", self.year, day).into()
+    }
+
+    /// Where the recorded answers for the real inputs are kept, relative to
+    /// the workspace root.
+    pub fn answers_path(&self) -> PathBuf {
+        format!("answers/{}.toml", self.year).into()
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input {
+    input
+        .downcast_ref()
+        .expect("input was not produced by this day's generator")
+}
+
+thread_local! {
+    /// Whether this thread is running a day, whose panics [`catch`] reports.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Stops the panics of days from being printed as they happen, since they
+/// are reported as errors anyway. Other panics are printed as before.
+pub fn quiet_panics() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !CATCHING.get() {
+            hook(info);
+        }
+    }));
+}
+
+fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(catching);
+    result.map_err(|payload| {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "solution panicked".to_string()
+        }
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{Day, ExampleRun, Part, Year};
+    use crate::{
+        parse::{lines, ParseError},
+        solution::{Answer, Example, Solution},
+    };
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 1;
+
+        type Input = Vec<i32>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            lines(1, input).map(|line| line.parse(line.text)).collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer, String> {
+            input
+                .iter()
+                .try_fold(0i32, |sum, &n| sum.checked_add(n))
+                .map(Answer::from)
+                .ok_or_else(|| "the sum overflows".to_string())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Answer, String> {
+            Ok((*input.iter().max().unwrap()).into())
+        }
+
+        fn examples() -> Vec<Example> {
+            vec![
+                Example::new("1\n2\n3").part1(6).part2(3),
+                Example::new("4\n4").part1(7),
+            ]
+        }
+    }
+
+    fn days() -> Vec<Day> {
+        vec![Day::of::<Sum>()]
+    }
+
+    #[test]
+    fn parse_part() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn run_day() {
+        let results = days()[0].run("1\n5\n3\n\n", &Part::ALL);
+        assert_eq!(
+            results,
+            vec![
+                (Part::One, Ok(Answer::Int(9))),
+                (Part::Two, Ok(Answer::Int(5)))
+            ]
+        );
+    }
+
+    #[test]
+    fn run_day_reports_invalid_input() {
+        let results = days()[0].run("199\nabc", &[Part::One]);
+        assert_eq!(
+            results,
+            vec![(
+                Part::One,
+                Err("invalid input: day1 line 2, column 1: expected i32 (found `abc`)".to_string())
+            )]
+        );
+    }
+
+    #[test]
+    fn run_day_reports_errors() {
+        let results = days()[0].run("2147483647\n1", &Part::ALL);
+        assert_eq!(
+            results[0],
+            (Part::One, Err("the sum overflows".to_string()))
+        );
+        assert_eq!(results[1], (Part::Two, Ok(Answer::Int(2147483647))));
+    }
+
+    #[test]
+    fn run_day_catches_panics() {
+        let results = days()[0].run("", &[Part::One, Part::Two]);
+        assert_eq!(results[0], (Part::One, Ok(Answer::Int(0))));
+        assert!(results[1].1.is_err());
+    }
+
+    #[test]
+    fn run_examples() {
+        let runs = days()[0].run_examples(&Part::ALL);
+        assert_eq!(
+            runs.iter()
+                .map(|run| (run.example, run.part, run.passed()))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, Part::One, true),
+                (1, Part::Two, true),
+                (2, Part::One, false),
+                (2, Part::Two, true)
+            ]
+        );
+        assert_eq!(
+            runs[2],
+            ExampleRun {
+                example: 2,
+                part: Part::One,
+                expected: Some(Answer::Int(7)),
+                found: Ok(Answer::Int(8)),
+            }
+        );
+    }
+
+    #[test]
+    fn paths() {
+        let year = Year { year: 2021, days };
+        assert_eq!(year.input_path(7).to_str(), Some("input/2021/day7.txt"));
+        assert_eq!(year.answers_path().to_str(), Some("answers/2021.toml"));
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+
+use crate::{parse::ParseError, render::Image};
+
+/// What a part returns: a number, the text of a code, or nothing at all for
+/// days that only have one real part.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Answer {
+    Int(i64),
+    /// Only used when the value does not fit into an `i64`.
+    BigInt(i128),
+    Text(String),
+    None,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::None => write!(f, "no answer"),
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        match i64::try_from(n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::BigInt(n),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(n: $int) -> Self {
+                (n as i128).into()
+            }
+        })*
+    };
+}
+
+from_int!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// An example input from a puzzle's text, along with the answers the text
+/// gives for it. Not every example comes with an answer for both parts.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Example {
+    pub fn new(input: &'static str) -> Self {
+        Example {
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub fn part1(mut self, answer: impl Into<Answer>) -> Self {
+        self.part1 = Some(answer.into());
+        self
+    }
+
+    pub fn part2(mut self, answer: impl Into<Answer>) -> Self {
+        self.part2 = Some(answer.into());
+        self
+    }
+}
+
+/// A day's puzzle: how to parse its input and how to solve both parts.
+pub trait Solution {
+    const DAY: u32;
+
+    type Input: 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solves the first part. An error means that the input parsed but has
+    /// no answer.
+    fn part1(input: &Self::Input) -> Result<Answer, String>;
+
+    /// Most days have two parts, the last day of a year only has one.
+    fn part2(_input: &Self::Input) -> Result<Answer, String> {
+        Ok(Answer::None)
+    }
+
+    /// Draws the state after `step` steps, or the final state if `step` is
+    /// `None`. What a step is depends on the day; days without intermediate
+    /// states ignore it, and days with nothing to look at return `None`.
+    fn render(_input: &Self::Input, _step: Option<usize>) -> Option<Image> {
+        None
+    }
+
+    /// An analysis of the input beyond the answers, for days that have one,
+    /// as JSON.
+    fn report(_input: &Self::Input) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// The examples from the puzzle text, so that a day can be tried out
+    /// on them without a real input.
+    fn examples() -> Vec<Example> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{Answer, Example};
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Answer::from(7u32), Answer::Int(7));
+        assert_eq!(Answer::from(-3i64), Answer::Int(-3));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+        assert_eq!(Answer::from(1i128 << 70), Answer::BigInt(1 << 70));
+        assert_eq!(
+            Answer::from("EBLUBRFH"),
+            Answer::Text("EBLUBRFH".to_string())
+        );
+    }
+
+    #[test]
+    fn examples() {
+        let example = Example::new("3,4,3,1,2").part2(26984457539i64);
+        assert_eq!(example.part1, None);
+        assert_eq!(example.part2, Some(Answer::Int(26984457539)));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::Int(-12).to_string(), "-12");
+        assert_eq!(
+            Answer::BigInt(1 << 70).to_string(),
+            "1180591620717411303424"
+        );
+        assert_eq!(Answer::Text("ABC".to_string()).to_string(), "ABC");
+        assert_eq!(Answer::None.to_string(), "no answer");
+    }
+}
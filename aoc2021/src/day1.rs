@@ -0,0 +1,471 @@
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+    num::NonZeroUsize,
+};
+
+use aoc::{
+    parse::{lines, Line, ParseError},
+    solution::{Answer, Example, Solution},
+};
+use serde::Serialize;
+
+/// The window of the second part.
+const SUM_WINDOW: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+#[aoc_generator(day1)]
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    lines(1, input).map(|line| line.parse(line.text)).collect()
+}
+
+#[aoc(day1, part1)]
+pub fn part1(input: &[i32]) -> usize {
+    count_increases(input.iter().copied(), NonZeroUsize::MIN)
+}
+
+#[aoc(day1, part2)]
+pub fn part2(input: &[i32]) -> usize {
+    count_increases(input.iter().copied(), SUM_WINDOW)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    /// The depth profile, with the moving average over the second part's
+    /// window.
+    fn report(input: &Self::Input) -> Option<serde_json::Value> {
+        serde_json::to_value(profile(input, SUM_WINDOW)).ok()
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(7).part2(5)]
+    }
+}
+
+/// How often the sum of a sliding window of `window` depths is larger than
+/// the sum of the window before it.
+pub fn count_increases(depths: impl IntoIterator<Item = i32>, window: NonZeroUsize) -> usize {
+    let mut increases = Increases::new(window);
+    depths.into_iter().for_each(|depth| increases.push(depth));
+    increases.count()
+}
+
+/// Like [`count_increases`], but reading the depths from `reader` one line at
+/// a time, so that a log of any size only ever needs `window` depths in
+/// memory.
+pub fn stream_increases(reader: impl BufRead, window: NonZeroUsize) -> Result<usize, DepthError> {
+    let mut increases = Increases::new(window);
+    for depth in Depths::new(reader) {
+        increases.push(depth?);
+    }
+    Ok(increases.count())
+}
+
+/// Counts window increases one depth at a time.
+///
+/// Two neighbouring windows share all but their first and last depths, so
+/// the later one has the larger sum exactly when the depth it gains is larger
+/// than the one it drops: only the last `window` depths need to be kept.
+#[derive(Debug, Clone)]
+pub struct Increases {
+    window: usize,
+    recent: VecDeque<i32>,
+    count: usize,
+}
+
+impl Increases {
+    pub fn new(window: NonZeroUsize) -> Self {
+        let window = window.get();
+        Increases {
+            window,
+            recent: VecDeque::with_capacity(window),
+            count: 0,
+        }
+    }
+
+    pub fn push(&mut self, depth: i32) {
+        if self.recent.len() == self.window {
+            let dropped = self.recent.pop_front().unwrap();
+            self.count += (depth > dropped) as usize;
+        }
+        self.recent.push_back(depth);
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// The depths in a sonar log, read lazily line by line.
+///
+/// Lines are normalised like [`aoc::parse::normalize`] does for whole inputs:
+/// line endings, a byte order mark, trailing whitespace and trailing blank
+/// lines are ignored, and only a blank line followed by more depths is an
+/// error.
+pub struct Depths<R> {
+    lines: io::Lines<R>,
+    number: usize,
+    blank: Option<usize>,
+}
+
+impl<R: BufRead> Depths<R> {
+    pub fn new(reader: R) -> Self {
+        Depths {
+            lines: reader.lines(),
+            number: 0,
+            blank: None,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Depths<R> {
+    type Item = Result<i32, DepthError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let text = match self.lines.next()? {
+                Ok(text) => text,
+                Err(err) => return Some(Err(DepthError::Io(err))),
+            };
+            self.number += 1;
+            let text = match self.number {
+                1 => text.strip_prefix('\u{feff}').unwrap_or(&text),
+                _ => &text,
+            }
+            .trim_end();
+
+            if text.is_empty() {
+                self.blank.get_or_insert(self.number);
+                continue;
+            }
+            let line = match self.blank.take() {
+                Some(number) => Line {
+                    day: 1,
+                    number,
+                    text: "",
+                },
+                None => Line {
+                    day: 1,
+                    number: self.number,
+                    text,
+                },
+            };
+            return Some(line.parse(line.text).map_err(DepthError::Parse));
+        }
+    }
+}
+
+/// Why a sonar log could not be read.
+#[derive(Debug)]
+pub enum DepthError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for DepthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DepthError::Io(err) => write!(f, "cannot read depths: {}", err),
+            DepthError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for DepthError {}
+
+/// The shape of the sea floor along a sonar sweep.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct Profile {
+    /// The maximal runs of strictly increasing depths, heading down.
+    pub increasing: Vec<Run>,
+    /// The maximal runs of strictly decreasing depths, heading up.
+    pub decreasing: Vec<Run>,
+    /// The first of the deepest measurements, if there are any.
+    pub deepest: Option<Measurement>,
+    /// The number of depths each moving average is taken over.
+    pub window: NonZeroUsize,
+    /// The mean of every `window` consecutive depths, in order.
+    pub moving_average: Vec<f64>,
+}
+
+/// Consecutive measurements, from index `start` on. `drop` is how much
+/// deeper the last one is than the first, negative for a run heading up.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+pub struct Run {
+    pub start: usize,
+    pub length: usize,
+    pub drop: i32,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+pub struct Measurement {
+    pub index: usize,
+    pub depth: i32,
+}
+
+/// Analyses the depths, with moving averages over `window` of them.
+pub fn profile(depths: &[i32], window: NonZeroUsize) -> Profile {
+    let deepest = depths
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|&(_, &depth)| depth)
+        .map(|(index, &depth)| Measurement { index, depth });
+    let moving_average = depths
+        .windows(window.get())
+        .map(|depths| depths.iter().map(|&d| d as f64).sum::<f64>() / window.get() as f64)
+        .collect();
+
+    Profile {
+        increasing: runs(depths, |a, b| a < b),
+        decreasing: runs(depths, |a, b| a > b),
+        deepest,
+        window,
+        moving_average,
+    }
+}
+
+/// The maximal runs of at least two depths where every step satisfies
+/// `step`.
+fn runs(depths: &[i32], step: impl Fn(i32, i32) -> bool) -> Vec<Run> {
+    let mut runs = vec![];
+    let mut start = 0;
+    for end in 1..=depths.len() {
+        if end < depths.len() && step(depths[end - 1], depths[end]) {
+            continue;
+        }
+        if end - start >= 2 {
+            runs.push(Run {
+                start,
+                length: end - start,
+                drop: depths[end - 1] - depths[start],
+            });
+        }
+        start = end;
+    }
+    runs
+}
+
+static INPUT: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+
+#[cfg(test)]
+pub mod tests {
+    use std::{
+        io::{BufReader, Cursor, Read},
+        num::NonZeroUsize,
+    };
+
+    use aoc::{
+        parse::{mangle, ParseError},
+        rng::Rng,
+        solution::Solution,
+    };
+
+    use super::{
+        count_increases, parse, part1, part2, profile, stream_increases, Day1, DepthError, Depths,
+        Measurement, Run, INPUT, SUM_WINDOW,
+    };
+    use crate::gen;
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(
+            input,
+            vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263]
+        );
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day1_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 7);
+    }
+
+    #[test]
+    fn day1_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 5);
+    }
+
+    #[test]
+    fn day1_windows() {
+        let mut rng = Rng::new(1);
+        for n in 1..=6 {
+            let window = NonZeroUsize::new(n).unwrap();
+            let depths = (0..200)
+                .map(|_| rng.range(0..=50) as i32)
+                .collect::<Vec<_>>();
+            let sums = depths
+                .windows(n)
+                .map(|w| w.iter().sum::<i32>())
+                .collect::<Vec<_>>();
+            let expected = sums.windows(2).filter(|s| s[0] < s[1]).count();
+            assert_eq!(count_increases(depths.iter().copied(), window), expected);
+        }
+        assert_eq!(count_increases([1, 2], NonZeroUsize::new(5).unwrap()), 0);
+    }
+
+    #[test]
+    fn day1_stream() {
+        for seed in 0..10 {
+            let text = gen::input(1, seed, 500).unwrap();
+            let depths = parse(&text).unwrap();
+            for window in [1, 3, 10].map(|n| NonZeroUsize::new(n).unwrap()) {
+                let streamed = stream_increases(Cursor::new(&text), window).unwrap();
+                assert_eq!(streamed, count_increases(depths.iter().copied(), window));
+            }
+        }
+        let mangled = mangle(INPUT);
+        assert_eq!(stream_increases(mangled.as_bytes(), SUM_WINDOW).unwrap(), 5);
+    }
+
+    #[test]
+    fn day1_stream_lazily() {
+        // Depths produced on the fly rather than held in a string, like a huge
+        // log read from disk: a slow descent of one metre per line.
+        let lines = (0..200_000u32).flat_map(|depth| format!("{}\n", depth).into_bytes());
+        let reader = BufReader::new(Bytes(lines));
+        assert_eq!(stream_increases(reader, SUM_WINDOW).unwrap(), 200_000 - 3);
+    }
+
+    struct Bytes<I>(I);
+
+    impl<I: Iterator<Item = u8>> Read for Bytes<I> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let mut n = 0;
+            for (slot, byte) in buf.iter_mut().zip(&mut self.0) {
+                *slot = byte;
+                n += 1;
+            }
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn day1_stream_errors() {
+        let error = |text: &str| match Depths::new(text.as_bytes()).find_map(Result::err) {
+            Some(DepthError::Parse(err)) => Some(err),
+            _ => None,
+        };
+        assert_eq!(
+            error("199\n200\n2o8\n"),
+            Some(ParseError::new(1, 3, 1, "2o8", "expected i32"))
+        );
+        assert_eq!(
+            error("199\r\n\r\n200\r\n"),
+            Some(ParseError::new(1, 2, 1, "", "expected i32"))
+        );
+        assert_eq!(error("199\n200\n\n\n"), None);
+        for text in ["199\n200\n2o8\n", "199\n\n200"] {
+            assert_eq!(error(text), parse(text).err());
+        }
+        assert_eq!(
+            stream_increases("1\n2\nx".as_bytes(), NonZeroUsize::MIN)
+                .unwrap_err()
+                .to_string(),
+            "day1 line 3, column 1: expected i32 (found `x`)"
+        );
+    }
+
+    #[test]
+    fn day1_profile() {
+        let depths = parse(INPUT).unwrap();
+        let profile = profile(&depths, SUM_WINDOW);
+        let run = |start, length, drop| Run {
+            start,
+            length,
+            drop,
+        };
+        assert_eq!(
+            profile.increasing,
+            vec![run(0, 4, 11), run(4, 4, 69), run(8, 2, 3)]
+        );
+        assert_eq!(profile.decreasing, vec![run(3, 2, -10), run(7, 2, -9)]);
+        assert_eq!(
+            profile.deepest,
+            Some(Measurement {
+                index: 7,
+                depth: 269
+            })
+        );
+        assert_eq!(profile.moving_average.len(), 8);
+        assert_eq!(profile.moving_average[0], 607.0 / 3.0);
+        assert_eq!(profile.moving_average[7], 792.0 / 3.0);
+    }
+
+    #[test]
+    fn day1_profile_edges() {
+        let empty = profile(&[], NonZeroUsize::new(2).unwrap());
+        assert_eq!((empty.increasing.len(), empty.deepest), (0, None));
+        assert!(empty.moving_average.is_empty());
+
+        // Equal depths end runs in both directions, and the first of several
+        // deepest points wins.
+        let flat = profile(&[5, 7, 7, 3, 7], NonZeroUsize::MIN);
+        assert_eq!(
+            flat.increasing,
+            vec![
+                Run {
+                    start: 0,
+                    length: 2,
+                    drop: 2
+                },
+                Run {
+                    start: 3,
+                    length: 2,
+                    drop: 4
+                }
+            ]
+        );
+        assert_eq!(flat.deepest.unwrap().index, 1);
+        assert_eq!(flat.moving_average, vec![5.0, 7.0, 7.0, 3.0, 7.0]);
+    }
+
+    #[test]
+    fn day1_profile_json() {
+        let json =
+            serde_json::to_value(profile(&[1, 3, 2], NonZeroUsize::new(2).unwrap())).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "increasing": [{"start": 0, "length": 2, "drop": 2}],
+                "decreasing": [{"start": 1, "length": 2, "drop": -1}],
+                "deepest": {"index": 1, "depth": 3},
+                "window": 2,
+                "moving_average": [2.0, 2.5],
+            })
+        );
+
+        let report = Day1::report(&parse(INPUT).unwrap()).unwrap();
+        assert_eq!(report["window"], 3);
+        assert_eq!(
+            report["deepest"],
+            serde_json::json!({"index": 7, "depth": 269})
+        );
+        assert_eq!(report["moving_average"][0], 607.0 / 3.0);
+    }
+}
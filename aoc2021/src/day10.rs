@@ -1,12 +1,26 @@
 use std::{collections::HashMap, vec};
 
+use aoc::{
+    parse::{lines, ParseError},
+    solution::{Answer, Example, Solution},
+};
+
 #[aoc_generator(day10)]
-fn generator_input(input: &str) -> Vec<Vec<char>> {
-    input.lines().map(|line| line.chars().collect()).collect()
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    lines(10, input)
+        .map(|line| {
+            line.chars()
+                .map(|(c, token)| match c {
+                    '(' | ')' | '[' | ']' | '{' | '}' | '<' | '>' => Ok(c),
+                    _ => Err(line.error(token, "expected a bracket")),
+                })
+                .collect()
+        })
+        .collect()
 }
 
 #[aoc(day10, part1)]
-fn part1(input: &[Vec<char>]) -> i32 {
+pub fn part1(input: &[Vec<char>]) -> i32 {
     let map = HashMap::from([(')', '('), (']', '['), ('}', '{'), ('>', '<')]);
     let score_map = HashMap::from([(')', 3), (']', 57), ('}', 1197), ('>', 25137)]);
     let mut score = 0;
@@ -30,7 +44,7 @@ fn part1(input: &[Vec<char>]) -> i32 {
 }
 
 #[aoc(day10, part2)]
-fn part2(input: &[Vec<char>]) -> u64 {
+pub fn part2(input: &[Vec<char>]) -> u64 {
     let map = HashMap::from([(')', '('), (']', '['), ('}', '{'), ('>', '<')]);
     let score_map = HashMap::from([('(', 1), ('[', 2), ('{', 3), ('<', 4)]);
     let mut scores = vec![];
@@ -62,11 +76,31 @@ fn part2(input: &[Vec<char>]) -> u64 {
     scores[scores.len() / 2]
 }
 
-#[cfg(test)]
-pub mod tests {
-    use super::{generator_input, part1, part2};
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
 
-    static INPUT: &str = r#"[({(<(())[]>[[{[]{<()<>>
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(26397).part2(288957)]
+    }
+}
+
+static INPUT: &str = r#"[({(<(())[]>[[{[]{<()<>>
 [(()[<>])]({[<{<<[]>>(
 {([(<{}[<>[]}>{[]{[(<()>
 (((({<>}<{<{<>}{[]{[]{}
@@ -77,9 +111,15 @@ pub mod tests {
 <{([([[(<>()){}]>(<<{{
 <{([{{}}[<[[[<>{}]]]>[]]"#;
 
+#[cfg(test)]
+pub mod tests {
+    use aoc::parse::mangle;
+
+    use super::{parse, part1, part2, INPUT};
+
     #[test]
     fn generator() {
-        let input = generator_input(INPUT);
+        let input = parse(INPUT).unwrap();
         assert_eq!(
             input[0],
             vec![
@@ -96,15 +136,20 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
     #[test]
     fn day10_part1() {
-        let input = generator_input(INPUT);
+        let input = parse(INPUT).unwrap();
         assert_eq!(part1(&input), 26397);
     }
 
     #[test]
     fn day10_part2() {
-        let input = generator_input(INPUT);
+        let input = parse(INPUT).unwrap();
         assert_eq!(part2(&input), 288957);
     }
 }
@@ -0,0 +1,148 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc::{
+    grid::{Grid, Pos},
+    parse::ParseError,
+    solution::{Answer, Example, Solution},
+};
+use itertools::Itertools;
+
+#[aoc_generator(day11)]
+pub fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse_digits(11, input, 10)
+}
+
+#[aoc(day11, part1)]
+pub fn part1(input: &Grid<u32>) -> usize {
+    let mut input = input.clone();
+    let mut flash_count = 0;
+
+    for _ in 0..100 {
+        flash_count += step(&mut input);
+    }
+
+    flash_count
+}
+
+#[aoc(day11, part2)]
+pub fn part2(input: &Grid<u32>) -> usize {
+    let octo_count = input.len();
+    let mut input = input.clone();
+    let mut step_count = 1;
+
+    while step(&mut input) != octo_count {
+        step_count += 1;
+    }
+
+    step_count
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(1656).part2(195)]
+    }
+}
+
+fn step(input: &mut Grid<u32>) -> usize {
+    let mut who_flashed: HashSet<Pos> = HashSet::new();
+    let mut new_flashes: VecDeque<Pos> = VecDeque::new();
+
+    for pos in input.positions() {
+        input[pos] += 1;
+        if input[pos] > 9 {
+            who_flashed.insert(pos);
+            new_flashes.push_back(pos);
+        }
+    }
+
+    while let Some(pos) = new_flashes.pop_front() {
+        for neighbor in input.neighbors8(pos).collect_vec() {
+            input[neighbor] += 1;
+            if input[neighbor] > 9 && !who_flashed.contains(&neighbor) {
+                who_flashed.insert(neighbor);
+                new_flashes.push_back(neighbor);
+            }
+        }
+    }
+
+    for &pos in who_flashed.iter() {
+        input[pos] = 0;
+    }
+
+    who_flashed.len()
+}
+
+static INPUT: &str = r#"5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+"#;
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::parse::mangle;
+    use itertools::Itertools;
+
+    use super::{parse, part1, part2, INPUT};
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(
+            input.rows().map(|row| row.to_vec()).collect_vec(),
+            vec![
+                vec![5, 4, 8, 3, 1, 4, 3, 2, 2, 3],
+                vec![2, 7, 4, 5, 8, 5, 4, 7, 1, 1],
+                vec![5, 2, 6, 4, 5, 5, 6, 1, 7, 3],
+                vec![6, 1, 4, 1, 3, 3, 6, 1, 4, 6],
+                vec![6, 3, 5, 7, 3, 8, 5, 4, 7, 8],
+                vec![4, 1, 6, 7, 5, 2, 4, 6, 4, 5],
+                vec![2, 1, 7, 6, 8, 4, 1, 7, 2, 1],
+                vec![6, 8, 8, 2, 8, 8, 1, 1, 3, 4],
+                vec![4, 8, 4, 6, 8, 4, 8, 5, 5, 4],
+                vec![5, 2, 8, 3, 7, 5, 1, 5, 2, 6],
+            ]
+        );
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day11_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 1656);
+    }
+
+    #[test]
+    fn day11_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 195);
+    }
+}
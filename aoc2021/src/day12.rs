@@ -0,0 +1,253 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::{
+    parse::{lines, ParseError},
+    solution::{Answer, Example, Solution},
+};
+
+#[aoc_generator(day12)]
+pub fn parse(input: &str) -> Result<Vec<(String, String)>, ParseError> {
+    lines(12, input)
+        .map(|line| {
+            let (left, right) = line.split_once(line.text, "-")?;
+            for cave in [left, right] {
+                if cave.is_empty() || !cave.chars().all(|c| c.is_ascii_alphabetic()) {
+                    return Err(line.error(cave, "expected a cave name"));
+                }
+            }
+            Ok((left.to_string(), right.to_string()))
+        })
+        .collect()
+}
+
+#[aoc(day12, part1)]
+pub fn part1(input: &[(String, String)]) -> i32 {
+    let (connections, big_caves) = parse_caves(input);
+    visit_next(
+        "start",
+        &connections,
+        &big_caves,
+        &HashSet::from(["start"]),
+        false,
+        false,
+    )
+}
+
+#[aoc(day12, part2)]
+pub fn part2(input: &[(String, String)]) -> i32 {
+    let (connections, big_caves) = parse_caves(input);
+    visit_next(
+        "start",
+        &connections,
+        &big_caves,
+        &HashSet::from(["start"]),
+        true,
+        false,
+    )
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Vec<(String, String)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(INPUT).part1(10).part2(36),
+            Example::new(INPUT2).part1(19).part2(103),
+        ]
+    }
+}
+
+fn parse_caves(input: &[(String, String)]) -> (HashMap<&str, Vec<&str>>, HashSet<&str>) {
+    let mut connections: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut big_caves: HashSet<&str> = HashSet::new();
+
+    for (left, right) in input {
+        for (l, r) in [(left, right), (right, left)] {
+            if l != "end" && r != "start" {
+                connections.entry(l).or_default().push(r);
+            }
+        }
+
+        for cave in [left, right] {
+            if cave.chars().next().unwrap().is_uppercase() {
+                big_caves.insert(cave);
+            }
+        }
+    }
+
+    (connections, big_caves)
+}
+
+fn visit_next(
+    current_cave: &str,
+    connections: &HashMap<&str, Vec<&str>>,
+    big_caves: &HashSet<&str>,
+    visited: &HashSet<&str>,
+    can_visit_small_cave_twice: bool,
+    did_visit_small_cave_twice: bool,
+) -> i32 {
+    if current_cave == "end" {
+        return 1;
+    }
+
+    let mut successful_paths = 0;
+
+    for cave in &connections[current_cave] {
+        let visited_and_small = visited.contains(cave) && !big_caves.contains(cave);
+        let can_visit_twice = can_visit_small_cave_twice && !did_visit_small_cave_twice;
+        let did_visit_twice = did_visit_small_cave_twice || can_visit_twice && visited_and_small;
+        let can_visit = !visited_and_small || can_visit_twice;
+
+        if can_visit {
+            let mut visited = visited.clone();
+            visited.insert(cave);
+            successful_paths += visit_next(
+                cave,
+                connections,
+                big_caves,
+                &visited,
+                can_visit_small_cave_twice,
+                did_visit_twice,
+            );
+        }
+    }
+
+    successful_paths
+}
+
+static INPUT: &str = r#"start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end"#;
+
+static INPUT2: &str = r#"dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc"#;
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::{
+        diff::{check, shrink_vec},
+        parse::mangle,
+    };
+    use itertools::Itertools;
+
+    use super::{parse, part1, part2, INPUT, INPUT2};
+    use crate::gen;
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(
+            input,
+            vec![
+                ("start".to_string(), "A".to_string()),
+                ("start".to_string(), "b".to_string()),
+                ("A".to_string(), "c".to_string()),
+                ("A".to_string(), "b".to_string()),
+                ("b".to_string(), "d".to_string()),
+                ("A".to_string(), "end".to_string()),
+                ("b".to_string(), "end".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day12_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 10);
+        let input2 = parse(INPUT2).unwrap();
+        assert_eq!(part1(&input2), 19);
+    }
+
+    #[test]
+    fn day12_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 36);
+    }
+
+    /// Enumerates every path explicitly and counts the distinct ones that
+    /// reach `end`.
+    fn enumerate_paths(input: &[(String, String)], twice: bool) -> i32 {
+        let small = |cave: &str| cave.chars().all(|c| c.is_ascii_lowercase());
+        let allowed = |path: &[&str]| {
+            let counts = path.iter().copied().filter(|cave| small(cave)).counts();
+            let repeated = counts.values().filter(|&&n| n > 1).collect_vec();
+            counts.get("start") == Some(&1)
+                && counts.get("end").copied().unwrap_or(0) <= 1
+                && match twice {
+                    true => repeated.len() <= 1 && repeated.iter().all(|&&n| n == 2),
+                    false => repeated.is_empty(),
+                }
+        };
+
+        let mut finished = Vec::new();
+        let mut paths = vec![vec!["start"]];
+        while let Some(path) = paths.pop() {
+            let last = *path.last().unwrap();
+            if last == "end" {
+                finished.push(path);
+                continue;
+            }
+            for (a, b) in input {
+                for (from, to) in [(a, b), (b, a)] {
+                    let mut next = path.clone();
+                    next.push(to);
+                    if from == last && allowed(&next) {
+                        paths.push(next);
+                    }
+                }
+            }
+        }
+        finished.into_iter().unique().count() as i32
+    }
+
+    #[test]
+    fn day12_differential() {
+        let paths = |input: &Vec<(String, String)>| {
+            (enumerate_paths(input, false), enumerate_paths(input, true))
+        };
+        let result = check(
+            100,
+            |rng| {
+                let caves = gen::input(12, rng.next_u64(), rng.range(1..=5) as usize).unwrap();
+                parse(&caves).unwrap()
+            },
+            |input| shrink_vec(input, |_| vec![]),
+            paths,
+            |input| (part1(input), part2(input)),
+        );
+        assert_eq!(result, Ok(()));
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashSet;
+
+use aoc::{
+    grid::Grid,
+    ocr,
+    parse::{blocks, ParseError},
+    render::{Color, Image},
+    solution::{Answer, Example, Solution},
+};
+use itertools::Itertools;
+
+#[aoc_generator(day13)]
+pub fn parse(input: &str) -> Result<(Paper, Vec<(Axis, i32)>), ParseError> {
+    let blocks = blocks(13, input);
+    let (dots_raw, instructions_raw) = match blocks.as_slice() {
+        [dots, instructions] => (dots, instructions),
+        [_] | [] => return Err(ParseError::end_of_input(13, input, "fold instructions")),
+        [_, _, extra, ..] => return Err(extra[0].error(extra[0].text, "unexpected third block")),
+    };
+    let dots = dots_raw
+        .iter()
+        .map(|line| {
+            let (x, y) = line.split_once(line.text, ",")?;
+            Ok((line.parse(x)?, line.parse(y)?))
+        })
+        .collect::<Result<HashSet<(i32, i32)>, ParseError>>()?;
+    let paper = Paper { dots };
+    let instructions = instructions_raw
+        .iter()
+        .map(|line| {
+            let fold = line.strip_prefix(line.text, "fold along ")?;
+            let (axis, coord) = line.split_once(fold, "=")?;
+            let axis = match axis {
+                "x" => Axis::X,
+                "y" => Axis::Y,
+                _ => return Err(line.error(axis, "expected `x` or `y`")),
+            };
+            Ok((axis, line.parse(coord)?))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    Ok((paper, instructions))
+}
+
+#[aoc(day13, part1)]
+pub fn part1((paper, instructions): &(Paper, Vec<(Axis, i32)>)) -> usize {
+    fold_paper(paper, instructions, 1).dots.len()
+}
+
+#[aoc(day13, part2)]
+pub fn part2((paper, instructions): &(Paper, Vec<(Axis, i32)>)) -> String {
+    let paper = fold_paper(paper, instructions, instructions.len());
+
+    // Fall back to the drawing itself if it does not spell any known letters.
+    let grid = paper.to_grid();
+    ocr::read(&grid).unwrap_or_else(|| grid.map(|&dot| if dot { '#' } else { '.' }).to_string())
+}
+
+fn fold_paper(paper: &Paper, instructions: &[(Axis, i32)], folds: usize) -> Paper {
+    let mut paper = paper.clone();
+    for (axis, coord) in instructions.iter().take(folds) {
+        paper.fold(axis, *coord);
+    }
+    paper
+}
+
+/// Draws the dots in white after the first `folds` folds (all of them by
+/// default).
+pub fn paper_image(
+    (paper, instructions): &(Paper, Vec<(Axis, i32)>),
+    folds: Option<usize>,
+) -> Image {
+    let folds = folds.unwrap_or(instructions.len());
+    fold_paper(paper, instructions, folds)
+        .to_grid()
+        .map(|&dot| if dot { Color::WHITE } else { Color::BLACK })
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Input = (Paper, Vec<(Axis, i32)>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT)
+            .part1(17)
+            .part2("#####\n#...#\n#...#\n#...#\n#####")]
+    }
+
+    fn render(input: &Self::Input, step: Option<usize>) -> Option<Image> {
+        Some(paper_image(input, step))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Paper {
+    dots: HashSet<(i32, i32)>,
+}
+
+impl Paper {
+    fn fold(&mut self, axis: &Axis, coord: i32) {
+        let dots = self.dots.drain().collect_vec();
+        for (x, y) in dots {
+            match axis {
+                Axis::X => {
+                    let new_x = if x < coord { x } else { x - 2 * (x - coord) };
+                    self.dots.insert((new_x, y));
+                }
+                Axis::Y => {
+                    let new_y = if y < coord { y } else { y - 2 * (y - coord) };
+                    self.dots.insert((x, new_y));
+                }
+            }
+        }
+    }
+
+    fn to_grid(&self) -> Grid<bool> {
+        let width = self.dots.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+        let height = self.dots.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+        Grid::from_fn(width as usize, height as usize, |(y, x)| {
+            self.dots.contains(&(x as i32, y as i32))
+        })
+    }
+}
+
+static INPUT: &str = r#"6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5"#;
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::{parse::mangle, render::Color};
+
+    use super::{paper_image, parse, part1, part2, Axis, INPUT};
+
+    #[test]
+    fn generator() {
+        let (paper, instructions) = parse(INPUT).unwrap();
+        assert!(paper.dots.contains(&(6, 10)));
+        assert!(paper.dots.contains(&(0, 14)));
+        assert!(paper.dots.contains(&(8, 10)));
+        assert!(paper.dots.contains(&(9, 0)));
+        assert!(!paper.dots.contains(&(0, 0)));
+        assert!(!paper.dots.contains(&(9, 9)));
+        assert_eq!(paper.to_grid().width(), 11);
+        assert_eq!(paper.to_grid().height(), 15);
+        assert_eq!(instructions, vec![(Axis::Y, 7), (Axis::X, 5)]);
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day13_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 17);
+    }
+
+    #[test]
+    fn day13_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), "#####\n#...#\n#...#\n#...#\n#####");
+    }
+
+    #[test]
+    fn day13_paper_image() {
+        let input = parse(INPUT).unwrap();
+        let unfolded = paper_image(&input, Some(0));
+        assert_eq!((unfolded.width(), unfolded.height()), (11, 15));
+        assert_eq!(unfolded[(10, 6)], Color::WHITE);
+        assert_eq!(unfolded[(0, 0)], Color::BLACK);
+
+        let folded = paper_image(&input, None);
+        assert_eq!((folded.width(), folded.height()), (5, 5));
+        assert_eq!(folded.values().filter(|&&c| c == Color::WHITE).count(), 16);
+    }
+}
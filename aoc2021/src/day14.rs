@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use aoc::{
+    parse::{blocks, ParseError},
+    solution::{Answer, Example, Solution},
+};
+use itertools::Itertools;
+
+#[aoc_generator(day14)]
+pub fn parse(input: &str) -> Result<(String, HashMap<String, char>), ParseError> {
+    let blocks = blocks(14, input);
+    let (polymer, rules_raw) = match blocks.as_slice() {
+        [polymer, rules] if polymer.len() == 1 => (polymer[0], rules),
+        [polymer, ..] if polymer.len() > 1 => {
+            return Err(polymer[1].error(polymer[1].text, "expected a blank line"))
+        }
+        [_, _, extra, ..] => return Err(extra[0].error(extra[0].text, "unexpected third block")),
+        _ => return Err(ParseError::end_of_input(14, input, "insertion rules")),
+    };
+
+    let rules = rules_raw
+        .iter()
+        .map(|line| {
+            let (l, r) = line.split_once(line.text, " -> ")?;
+            if l.chars().count() != 2 {
+                return Err(line.error(l, "expected a pair of elements"));
+            }
+            Ok((l.to_string(), line.parse(r)?))
+        })
+        .collect::<Result<HashMap<_, _>, ParseError>>()?;
+
+    Ok((polymer.text.to_string(), rules))
+}
+
+#[aoc(day14, part1)]
+pub fn part1((polymer, rules): &(String, HashMap<String, char>)) -> u64 {
+    find_formula(polymer, rules, 10)
+}
+
+#[aoc(day14, part2)]
+pub fn part2((polymer, rules): &(String, HashMap<String, char>)) -> u64 {
+    find_formula(polymer, rules, 40)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type Input = (String, HashMap<String, char>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(1588).part2(2188189693529i64)]
+    }
+}
+
+fn find_formula(polymer: &str, rules: &HashMap<String, char>, steps: i32) -> u64 {
+    let mut counts: HashMap<char, u64> = HashMap::new();
+    let mut pairs: HashMap<(char, char), u64> = HashMap::new();
+
+    for elem in polymer.chars() {
+        *counts.entry(elem).or_insert(0) += 1;
+    }
+
+    for pair in polymer.chars().tuple_windows() {
+        *pairs.entry(pair).or_insert(0) += 1;
+    }
+
+    for _ in 0..steps {
+        step(rules, &mut counts, &mut pairs);
+    }
+
+    let most = counts.values().max().unwrap();
+    let least = counts.values().min().unwrap();
+
+    most - least
+}
+
+fn step(
+    rules: &HashMap<String, char>,
+    counts: &mut HashMap<char, u64>,
+    pairs: &mut HashMap<(char, char), u64>,
+) {
+    let prev_pairs = pairs.drain().collect_vec();
+    for ((left, right), count) in prev_pairs {
+        let middle = rules[&format!("{}{}", left, right)];
+        *counts.entry(middle).or_insert(0) += count;
+        *pairs.entry((left, middle)).or_insert(0) += count;
+        *pairs.entry((middle, right)).or_insert(0) += count;
+    }
+}
+
+static INPUT: &str = r#"NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C"#;
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::parse::mangle;
+
+    use super::{parse, part1, part2, INPUT};
+
+    #[test]
+    fn generator() {
+        let (polymer, rules) = parse(INPUT).unwrap();
+        assert_eq!(polymer, "NNCB");
+        assert_eq!(rules["CH"], 'B');
+        assert_eq!(rules["HH"], 'N');
+        assert_eq!(rules["CN"], 'C');
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day14_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 1588);
+    }
+
+    #[test]
+    fn day14_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 2188189693529);
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+use aoc::{
+    grid::{Grid, Pos},
+    parse::ParseError,
+    render::{Color, Image},
+    solution::{Answer, Example, Solution},
+};
+
+#[aoc_generator(day15)]
+pub fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    let grid = Grid::parse_digits(15, input, 10)?;
+    // The path runs between the corners, so there have to be some.
+    if grid.width() == 0 || grid.height() == 0 {
+        return Err(ParseError::end_of_input(15, input, "a risk map"));
+    }
+    Ok(grid)
+}
+
+#[aoc(day15, part1)]
+pub fn part1(input: &Grid<u32>) -> u32 {
+    corner_to_corner(input).0
+}
+
+#[aoc(day15, part2)]
+pub fn part2(input: &Grid<u32>) -> u32 {
+    let (orig_h, orig_w) = (input.height(), input.width());
+    let grid = Grid::from_fn(orig_w * 5, orig_h * 5, |(i, j)| {
+        let tile = (i / orig_h + j / orig_w) as u32;
+        let mut digit = input[(i % orig_h, j % orig_w)] + tile;
+        while digit > 9 {
+            digit -= 9;
+        }
+        digit
+    });
+    corner_to_corner(&grid).0
+}
+
+/// Draws the cheapest path in red over the risk map, shaded from black for
+/// risk 1 to white for risk 9.
+pub fn path_image(input: &Grid<u32>) -> Image {
+    let (_, path) = corner_to_corner(input);
+    let mut image = input.map(|&risk| {
+        let shade = (risk.saturating_sub(1) * 255 / 8) as u8;
+        Color::rgb(shade, shade, shade)
+    });
+    for pos in path {
+        image[pos] = Color::RED;
+    }
+    image
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(40).part2(315)]
+    }
+
+    fn render(input: &Self::Input, _step: Option<usize>) -> Option<Image> {
+        Some(path_image(input))
+    }
+}
+
+/// The lowest total risk from the top left to the bottom right corner, and
+/// the path that has it.
+fn corner_to_corner(input: &Grid<u32>) -> (u32, Vec<Pos>) {
+    let destination = (input.height() - 1, input.width() - 1);
+    dijkstra(input, (0, 0), destination).expect("the corners are connected")
+}
+
+/// The cheapest path from `source` to `destination` and its cost, where
+/// entering a cell costs its value. Moves follow the grid's topology, and
+/// `None` means that `destination` cannot be reached.
+pub fn dijkstra(input: &Grid<u32>, source: Pos, destination: Pos) -> Option<(u32, Vec<Pos>)> {
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((0, source)));
+
+    let mut min_dist = HashMap::from([(source, 0)]);
+    let mut came_from = HashMap::new();
+    let mut visited = HashSet::new();
+
+    while let Some(Reverse((dist, node))) = heap.pop() {
+        if node == destination {
+            let mut path = vec![node];
+            while let Some(&previous) = came_from.get(path.last().unwrap()) {
+                path.push(previous);
+            }
+            path.reverse();
+            return Some((dist, path));
+        }
+
+        if visited.contains(&node) {
+            continue;
+        }
+
+        visited.insert(node);
+
+        for neighbor in input.neighbors4(node) {
+            if visited.contains(&neighbor) {
+                continue;
+            }
+
+            let new_dist = dist + input[neighbor];
+            if new_dist < *min_dist.get(&neighbor).unwrap_or(&u32::MAX) {
+                min_dist.insert(neighbor, new_dist);
+                came_from.insert(neighbor, node);
+                heap.push(Reverse((new_dist, neighbor)));
+            }
+        }
+    }
+
+    None
+}
+
+static INPUT: &str = r#"1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+"#;
+
+#[cfg(test)]
+pub mod tests {
+    use itertools::Itertools;
+
+    use aoc::{parse::mangle, render::Color};
+
+    use super::{dijkstra, parse, part1, part2, path_image, INPUT};
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(
+            input.rows().map(|row| row.to_vec()).collect_vec(),
+            vec![
+                vec![1, 1, 6, 3, 7, 5, 1, 7, 4, 2],
+                vec![1, 3, 8, 1, 3, 7, 3, 6, 7, 2],
+                vec![2, 1, 3, 6, 5, 1, 1, 3, 2, 8],
+                vec![3, 6, 9, 4, 9, 3, 1, 5, 6, 9],
+                vec![7, 4, 6, 3, 4, 1, 7, 1, 1, 1],
+                vec![1, 3, 1, 9, 1, 2, 8, 1, 3, 7],
+                vec![1, 3, 5, 9, 9, 1, 2, 4, 2, 1],
+                vec![3, 1, 2, 5, 4, 2, 1, 6, 3, 9],
+                vec![1, 2, 9, 3, 1, 3, 8, 5, 2, 1],
+                vec![2, 3, 1, 1, 9, 4, 4, 5, 8, 1],
+            ]
+        );
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn generator_error() {
+        for input in ["", "\n\n"] {
+            assert_eq!(
+                parse(input).unwrap_err().reason,
+                "expected a risk map, found end of input"
+            );
+        }
+    }
+
+    #[test]
+    fn day15_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 40);
+    }
+
+    #[test]
+    fn day15_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 315);
+    }
+
+    #[test]
+    fn day15_dijkstra() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(
+            dijkstra(&input, (0, 0), (0, 2)),
+            Some((7, vec![(0, 0), (0, 1), (0, 2)]))
+        );
+        assert_eq!(dijkstra(&input, (9, 9), (9, 9)), Some((0, vec![(9, 9)])));
+        assert_eq!(dijkstra(&input, (0, 0), (10, 0)), None);
+    }
+
+    #[test]
+    fn day15_path_image() {
+        let input = parse(INPUT).unwrap();
+        let image = path_image(&input);
+        assert_eq!(image[(0, 0)], Color::RED);
+        assert_eq!(image[(9, 9)], Color::RED);
+        assert_eq!(image[(0, 9)], Color::rgb(31, 31, 31));
+        assert_eq!(image[(3, 2)], Color::WHITE);
+        assert!(image.values().filter(|&&c| c == Color::RED).count() >= 19);
+    }
+}
@@ -0,0 +1,396 @@
+use std::fmt;
+
+use aoc::{
+    parse::{lines, ParseError},
+    solution::{Answer, Example, Solution},
+};
+
+#[aoc_generator(day16)]
+pub fn parse(input: &str) -> Result<Packet, ParseError> {
+    let mut lines = lines(16, input);
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(16, input, "a hexadecimal transmission"))?;
+    if let Some(extra) = lines.next() {
+        return Err(extra.error(extra.text, "expected a single line"));
+    }
+    if let Some((_, token)) = line.chars().find(|&(c, _)| !c.is_ascii_hexdigit()) {
+        return Err(line.error(token, "expected a hexadecimal digit"));
+    }
+
+    decode(line.text).map_err(|invalid| {
+        let digit = line.text.get(invalid.digit..=invalid.digit);
+        line.error(
+            digit.unwrap_or(&line.text[line.text.len()..]),
+            invalid.reason,
+        )
+    })
+}
+
+#[aoc(day16, part1)]
+pub fn part1(input: &Packet) -> i32 {
+    sum_of_versions(input)
+}
+
+#[aoc(day16, part2)]
+pub fn part2(input: &Packet) -> u64 {
+    eval(input)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    type Input = Packet;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(INPUT).part1(6),
+            Example::new("8A004A801A8002F478").part1(16),
+            Example::new("620080001611562C8802118E34").part1(12),
+            Example::new("C0015000016115A2E0802F182340").part1(23),
+            Example::new("A0016C880162017C3686B18A3D4780").part1(31),
+            Example::new("C200B40A82").part2(3),
+            Example::new("04005AC33890").part2(54),
+            Example::new("880086C3E88112").part2(7),
+            Example::new("CE00C43D881120").part2(9),
+            Example::new("D8005AC2A8F0").part2(1),
+            Example::new("F600BC2D8F").part2(0),
+            Example::new("9C005AC2F8F0").part2(0),
+            Example::new("9C0141080250320F1802104A08").part2(1),
+        ]
+    }
+}
+
+/// Why a transmission could not be decoded, and the digit where the packet at
+/// fault starts, or the number of digits if it starts after the end.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DecodeError {
+    pub digit: usize,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "digit {}: {}", self.digit + 1, self.reason)
+    }
+}
+
+/// Decodes the outermost packet of a hexadecimal BITS transmission.
+pub fn decode(hex: &str) -> Result<Packet, DecodeError> {
+    let mut bits = vec![];
+    for (digit, c) in hex.chars().enumerate() {
+        let value = c.to_digit(16).ok_or(DecodeError {
+            digit,
+            reason: "expected a hexadecimal digit",
+        })?;
+        bits.extend((0..4).rev().map(|i| value >> i & 1 == 1));
+    }
+    let mut reader = Reader { bits, position: 0 };
+    let end = reader.bits.len();
+    let packet = reader.packet(end)?;
+    // The transmission is padded to whole digits with zeros.
+    if reader.bits[reader.position..].contains(&true) {
+        return Err(DecodeError {
+            digit: reader.position / 4,
+            reason: "expected only zeros after the packet",
+        });
+    }
+    Ok(packet)
+}
+
+struct Reader {
+    bits: Vec<bool>,
+    position: usize,
+}
+
+impl Reader {
+    /// The next `count` bits as a number, if they all come before `end`.
+    fn number(&mut self, count: usize, end: usize) -> Option<u64> {
+        if self.position + count > end {
+            return None;
+        }
+        let bits = &self.bits[self.position..self.position + count];
+        self.position += count;
+        Some(bits.iter().fold(0, |n, &bit| (n << 1) | bit as u64))
+    }
+
+    /// The packet starting at the current position and ending before `end`.
+    fn packet(&mut self, end: usize) -> Result<Packet, DecodeError> {
+        let start = self.position / 4;
+        let invalid = |reason| DecodeError {
+            digit: start,
+            reason,
+        };
+        let truncated = invalid("truncated packet");
+        let version = self.number(3, end).ok_or(truncated)? as u8;
+        let type_id = self.number(3, end).ok_or(truncated)?;
+
+        if type_id == 4 {
+            let mut value = 0u64;
+            loop {
+                let group = self.number(5, end).ok_or(truncated)?;
+                if value >> 60 != 0 {
+                    return Err(invalid("literal value too large"));
+                }
+                value = (value << 4) | (group & 0xf);
+                if group >> 4 == 0 {
+                    break;
+                }
+            }
+            return Ok(Packet::Literal { version, value });
+        }
+
+        let operator = match type_id {
+            0 => Operator::Sum,
+            1 => Operator::Product,
+            2 => Operator::Minimum,
+            3 => Operator::Maximum,
+            5 => Operator::Greater,
+            6 => Operator::Lesser,
+            _ => Operator::Equal,
+        };
+        let mut sub_packets = vec![];
+        if self.number(1, end).ok_or(truncated)? == 0 {
+            let length = self.number(15, end).ok_or(truncated)? as usize;
+            let sub_end = self.position + length;
+            if sub_end > end {
+                return Err(truncated);
+            }
+            while self.position < sub_end {
+                sub_packets.push(self.packet(sub_end)?);
+            }
+        } else {
+            for _ in 0..self.number(11, end).ok_or(truncated)? {
+                sub_packets.push(self.packet(end)?);
+            }
+        }
+
+        match operator {
+            Operator::Greater | Operator::Lesser | Operator::Equal if sub_packets.len() != 2 => {
+                Err(invalid("expected two sub-packets"))
+            }
+            _ if sub_packets.is_empty() => Err(invalid("expected sub-packets")),
+            _ => Ok(Packet::Operator {
+                version,
+                operator,
+                sub_packets,
+            }),
+        }
+    }
+}
+
+/// The version of `packet` plus those of all packets nested in it.
+pub fn sum_of_versions(packet: &Packet) -> i32 {
+    match packet {
+        Packet::Literal { version, .. } => *version as i32,
+        Packet::Operator {
+            version,
+            sub_packets,
+            ..
+        } => sub_packets
+            .iter()
+            .fold(*version as i32, |acc, p| acc + sum_of_versions(p)),
+    }
+}
+
+/// The value of the expression `packet` encodes.
+pub fn eval(packet: &Packet) -> u64 {
+    let (operator, sub_packets) = match packet {
+        Packet::Literal { value, .. } => return *value,
+        Packet::Operator {
+            operator,
+            sub_packets,
+            ..
+        } => (operator, sub_packets),
+    };
+    let mut values = sub_packets.iter().map(eval);
+    match operator {
+        Operator::Sum => values.sum(),
+        Operator::Product => values.product(),
+        Operator::Minimum => values.min().unwrap(),
+        Operator::Maximum => values.max().unwrap(),
+        Operator::Greater => (values.next() > values.next()) as u64,
+        Operator::Lesser => (values.next() < values.next()) as u64,
+        Operator::Equal => (values.next() == values.next()) as u64,
+    }
+}
+
+/// A BITS packet. Decoded packets have at least one sub-packet, and exactly
+/// two for comparisons.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Packet {
+    Literal {
+        version: u8,
+        value: u64,
+    },
+    Operator {
+        version: u8,
+        operator: Operator,
+        sub_packets: Vec<Packet>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operator {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    Greater,
+    Lesser,
+    Equal,
+}
+
+static INPUT: &str = r#"D2FE28"#;
+
+#[cfg(test)]
+pub mod tests {
+    use super::{decode, eval, parse, part1, part2, DecodeError, Operator, Packet, INPUT};
+    use aoc::parse::{mangle, ParseError};
+
+    fn literal(version: u8, value: u64) -> Packet {
+        Packet::Literal { version, value }
+    }
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(input, literal(6, 2021));
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn generator_error() {
+        assert_eq!(
+            parse("D2FG28"),
+            Err(ParseError::new(
+                16,
+                1,
+                4,
+                "G",
+                "expected a hexadecimal digit"
+            ))
+        );
+        assert_eq!(
+            parse("").unwrap_err().reason,
+            "expected a hexadecimal transmission, found end of input"
+        );
+        for (input, column, digit) in [("FF", 1, "F"), ("0", 1, "0"), ("D2FE", 1, "D")] {
+            assert_eq!(
+                parse(input),
+                Err(ParseError::new(16, 1, column, digit, "truncated packet"))
+            );
+        }
+        // The operator's sub-packets take more bits than there are left.
+        assert_eq!(
+            parse("38006F4529"),
+            Err(ParseError::new(16, 1, 1, "3", "truncated packet"))
+        );
+        // An operator that says it has three sub-packets after the last one.
+        assert_eq!(
+            parse("EE00D40C82"),
+            Err(ParseError::new(16, 1, 11, "", "truncated packet"))
+        );
+        assert_eq!(
+            parse("D2FE2801"),
+            Err(ParseError::new(
+                16,
+                1,
+                6,
+                "8",
+                "expected only zeros after the packet"
+            ))
+        );
+        assert_eq!(
+            parse("C2000"),
+            Err(ParseError::new(16, 1, 1, "C", "expected sub-packets"))
+        );
+        assert_eq!(
+            parse("D6004C08"),
+            Err(ParseError::new(16, 1, 1, "D", "expected two sub-packets"))
+        );
+    }
+
+    #[test]
+    fn day16_decode() {
+        assert_eq!(decode(INPUT), Ok(literal(6, 2021)));
+
+        let packet = decode("38006f45291200").unwrap();
+        assert_eq!(
+            packet,
+            Packet::Operator {
+                version: 1,
+                operator: Operator::Lesser,
+                sub_packets: vec![literal(6, 10), literal(2, 20)],
+            }
+        );
+        assert_eq!(eval(&packet), 1);
+        let truncated = |digit| {
+            Err(DecodeError {
+                digit,
+                reason: "truncated packet",
+            })
+        };
+        assert_eq!(decode(""), truncated(0));
+        assert_eq!(decode("D2FE"), truncated(0));
+        assert_eq!(
+            decode("D2FE").unwrap_err().to_string(),
+            "digit 1: truncated packet"
+        );
+
+        // Literals hold up to 64 bits.
+        assert_eq!(decode("13FFFFFFFFFFFFFFFFFFBC"), Ok(literal(0, u64::MAX)));
+        assert_eq!(
+            decode("13FFFFFFFFFFFFFFFFFFFDE").unwrap_err().reason,
+            "literal value too large"
+        );
+    }
+
+    #[test]
+    fn day16_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 6);
+        for (input, answer) in [
+            ("8A004A801A8002F478", 16),
+            ("620080001611562C8802118E34", 12),
+            ("C0015000016115A2E0802F182340", 23),
+            ("A0016C880162017C3686B18A3D4780", 31),
+        ] {
+            assert_eq!(part1(&parse(input).unwrap()), answer);
+        }
+    }
+
+    #[test]
+    fn day16_part2() {
+        for (input, answer) in [
+            ("C200B40A82", 3),
+            ("04005AC33890", 54),
+            ("880086C3E88112", 7),
+            ("CE00C43D881120", 9),
+            ("D8005AC2A8F0", 1),
+            ("F600BC2D8F", 0),
+            ("9C005AC2F8F0", 0),
+            ("9C0141080250320F1802104A08", 1),
+        ] {
+            assert_eq!(part2(&parse(input).unwrap()), answer);
+        }
+    }
+}
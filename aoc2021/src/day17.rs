@@ -0,0 +1,175 @@
+use std::{
+    cmp::Ordering::{Equal, Greater, Less},
+    ops::RangeInclusive,
+};
+
+use aoc::{
+    parse::{lines, ParseError},
+    solution::{Answer, Example, Solution},
+};
+
+#[aoc_generator(day17)]
+pub fn parse(input: &str) -> Result<Area, ParseError> {
+    let line = lines(17, input)
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(17, input, "a target area"))?;
+    let (l, r) = line.split_once(line.strip_prefix(line.text, "target area: ")?, ", ")?;
+    let (x1, x2) = line.split_once(line.strip_prefix(l, "x=")?, "..")?;
+    let (y1, y2) = line.split_once(line.strip_prefix(r, "y=")?, "..")?;
+
+    Ok(Area::new(
+        line.parse(x1)?..=line.parse(x2)?,
+        line.parse(y1)?..=line.parse(y2)?,
+    ))
+}
+
+#[aoc(day17, part1)]
+pub fn part1(area: &Area) -> i32 {
+    let (max_y, _) = brute_force_shots(area);
+    max_y
+}
+
+#[aoc(day17, part2)]
+pub fn part2(area: &Area) -> i32 {
+    let (_, vel_count) = brute_force_shots(area);
+    vel_count
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    type Input = Area;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(45).part2(112)]
+    }
+}
+
+fn brute_force_shots(area: &Area) -> (i32, i32) {
+    let mut max_y = 0;
+    let mut vel_count = 0;
+    for x in -1000..1000 {
+        for y in -1000..1000 {
+            if let Some(local_max_y) = shoot_probe(area, (x, y)) {
+                max_y = max_y.max(local_max_y);
+                vel_count += 1;
+            }
+        }
+    }
+    (max_y, vel_count)
+}
+
+fn shoot_probe(area: &Area, (vel_x, vel_y): (i32, i32)) -> Option<i32> {
+    let mut pos = Point { x: 0, y: 0 };
+    let mut vel = Point { x: vel_x, y: vel_y };
+    let mut max_y = 0;
+
+    while !area.is_inside(&pos) && (pos.y > area.br.y - 1000) {
+        max_y = max_y.max(pos.y);
+        pos.x += vel.x;
+        pos.y += vel.y;
+        vel.x += match vel.x.cmp(&0) {
+            Less => 1,
+            Equal => 0,
+            Greater => -1,
+        };
+        vel.y -= 1;
+    }
+
+    if area.is_inside(&pos) {
+        return Some(max_y);
+    }
+
+    None
+}
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+/// The target area, from its top left to its bottom right corner.
+#[derive(Debug, PartialEq)]
+pub struct Area {
+    tl: Point,
+    br: Point,
+}
+
+impl Area {
+    pub fn new(x: RangeInclusive<i32>, y: RangeInclusive<i32>) -> Self {
+        Area {
+            tl: Point {
+                x: *x.start(),
+                y: *y.end(),
+            },
+            br: Point {
+                x: *x.end(),
+                y: *y.start(),
+            },
+        }
+    }
+
+    pub fn x(&self) -> RangeInclusive<i32> {
+        self.tl.x..=self.br.x
+    }
+
+    pub fn y(&self) -> RangeInclusive<i32> {
+        self.br.y..=self.tl.y
+    }
+
+    fn is_inside(&self, point: &Point) -> bool {
+        self.tl.x <= point.x && point.x <= self.br.x && self.br.y <= point.y && point.y <= self.tl.y
+    }
+}
+
+static INPUT: &str = "target area: x=20..30, y=-10..-5";
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::parse::mangle;
+
+    use super::{parse, part1, part2, Area, Point, INPUT};
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(input.tl, Point { x: 20, y: -5 });
+        assert_eq!(input.br, Point { x: 30, y: -10 });
+        assert_eq!((input.x(), input.y()), (20..=30, -10..=-5));
+        assert_eq!(Area::new(20..=30, -10..=-5), input);
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    #[ignore] // expensive brute-force :)
+    fn day17_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 45);
+    }
+
+    #[test]
+    #[ignore] // expensive brute-force :)
+    fn day17_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 112);
+    }
+}
@@ -0,0 +1,447 @@
+use std::{fmt, iter::Peekable, ops::Add, str::FromStr};
+
+use aoc::{
+    parse::{lines, Line, ParseError},
+    solution::{Answer, Example, Solution},
+};
+use itertools::Itertools;
+
+#[aoc_generator(day18)]
+pub fn parse(input: &str) -> Result<Vec<SnailfishNumber>, ParseError> {
+    lines(18, input).map(|line| parse_line(&line)).collect()
+}
+
+#[aoc(day18, part1)]
+pub fn part1(input: &[SnailfishNumber]) -> u64 {
+    input
+        .iter()
+        .cloned()
+        .reduce(|sum, number| sum + number)
+        .map_or(0, |sum| sum.magnitude())
+}
+
+#[aoc(day18, part2)]
+pub fn part2(input: &[SnailfishNumber]) -> u64 {
+    input
+        .iter()
+        .permutations(2)
+        .map(|pair| (pair[0].clone() + pair[1].clone()).magnitude())
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+
+    type Input = Vec<SnailfishNumber>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(INPUT).part1(4140).part2(3993),
+            Example::new(INPUT_2).part1(3488),
+        ]
+    }
+}
+
+fn parse_line(line: &Line) -> Result<SnailfishNumber, ParseError> {
+    let mut chars = line.chars().peekable();
+    let number = parse_element(line, &mut chars)?;
+    match chars.next() {
+        Some((_, token)) => Err(line.error(token, "expected end of line")),
+        None => Ok(number),
+    }
+}
+
+fn parse_element<'a>(
+    line: &Line<'a>,
+    chars: &mut Peekable<impl Iterator<Item = (char, &'a str)>>,
+) -> Result<SnailfishNumber, ParseError> {
+    match chars.peek() {
+        Some(('[', _)) => {
+            chars.next();
+            let left = parse_element(line, chars)?;
+            expect(line, chars, ',')?;
+            let right = parse_element(line, chars)?;
+            expect(line, chars, ']')?;
+            Ok(SnailfishNumber::pair(left, right))
+        }
+        Some((c, _)) if c.is_ascii_digit() => {
+            let mut value = 0;
+            while let Some(digit) = chars.peek().and_then(|(c, _)| c.to_digit(10)) {
+                value = value * 10 + digit as u64;
+                chars.next();
+            }
+            Ok(SnailfishNumber::Regular(value))
+        }
+        Some(&(_, token)) => Err(line.error(token, "expected `[` or a number")),
+        None => Err(line.error(
+            &line.text[line.text.len()..],
+            "expected `[` or a number, found end of line",
+        )),
+    }
+}
+
+fn expect<'a>(
+    line: &Line<'a>,
+    chars: &mut impl Iterator<Item = (char, &'a str)>,
+    expected: char,
+) -> Result<(), ParseError> {
+    match chars.next() {
+        Some((c, _)) if c == expected => Ok(()),
+        Some((_, token)) => Err(line.error(token, format!("expected `{}`", expected))),
+        None => Err(line.error(
+            &line.text[line.text.len()..],
+            format!("expected `{}`, found end of line", expected),
+        )),
+    }
+}
+
+/// Either a regular number or a pair of snailfish numbers.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SnailfishNumber {
+    Regular(u64),
+    Pair(Box<SnailfishNumber>, Box<SnailfishNumber>),
+}
+
+/// What a single reduction step did.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Action {
+    Explode,
+    Split,
+}
+
+impl SnailfishNumber {
+    /// The pair `[left,right]`, not reduced.
+    pub fn pair(left: SnailfishNumber, right: SnailfishNumber) -> Self {
+        SnailfishNumber::Pair(Box::new(left), Box::new(right))
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        match self {
+            SnailfishNumber::Regular(value) => *value,
+            SnailfishNumber::Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+
+    /// The reduction of this number one action at a time, each along with
+    /// the number right after it.
+    pub fn reduction(self) -> Reduction {
+        Reduction { number: self }
+    }
+
+    /// Explodes the leftmost pair nested inside four pairs or, if there is
+    /// none, splits the leftmost regular number of 10 or more.
+    fn reduce_once(&mut self) -> Option<Action> {
+        if self.explode(0).is_some() {
+            Some(Action::Explode)
+        } else if self.split() {
+            Some(Action::Split)
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the exploding pair with 0 and returns the values that still
+    /// have to be added to the regular numbers left and right of it.
+    fn explode(&mut self, depth: usize) -> Option<(Option<u64>, Option<u64>)> {
+        let SnailfishNumber::Pair(left, right) = self else {
+            return None;
+        };
+        if depth >= 4 {
+            if let (SnailfishNumber::Regular(l), SnailfishNumber::Regular(r)) = (&**left, &**right)
+            {
+                let carry = (Some(*l), Some(*r));
+                *self = SnailfishNumber::Regular(0);
+                return Some(carry);
+            }
+        }
+        if let Some((carry_left, carry_right)) = left.explode(depth + 1) {
+            if let Some(value) = carry_right {
+                right.add_to_edge(value, true);
+            }
+            return Some((carry_left, None));
+        }
+        if let Some((carry_left, carry_right)) = right.explode(depth + 1) {
+            if let Some(value) = carry_left {
+                left.add_to_edge(value, false);
+            }
+            return Some((None, carry_right));
+        }
+        None
+    }
+
+    /// Adds `value` to the leftmost or rightmost regular number.
+    fn add_to_edge(&mut self, value: u64, leftmost: bool) {
+        match self {
+            SnailfishNumber::Regular(n) => *n += value,
+            SnailfishNumber::Pair(left, _) if leftmost => left.add_to_edge(value, leftmost),
+            SnailfishNumber::Pair(_, right) => right.add_to_edge(value, leftmost),
+        }
+    }
+
+    fn split(&mut self) -> bool {
+        match self {
+            SnailfishNumber::Regular(n) if *n >= 10 => {
+                *self = SnailfishNumber::pair(
+                    SnailfishNumber::Regular(*n / 2),
+                    SnailfishNumber::Regular(n.div_ceil(2)),
+                );
+                true
+            }
+            SnailfishNumber::Regular(_) => false,
+            SnailfishNumber::Pair(left, right) => left.split() || right.split(),
+        }
+    }
+}
+
+impl Add for SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    /// The reduced sum.
+    fn add(self, rhs: SnailfishNumber) -> SnailfishNumber {
+        SnailfishNumber::pair(self, rhs).reduction().finish()
+    }
+}
+
+impl FromStr for SnailfishNumber {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_line(&Line {
+            day: 18,
+            number: 1,
+            text: s,
+        })
+    }
+}
+
+impl fmt::Display for SnailfishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SnailfishNumber::Regular(value) => write!(f, "{}", value),
+            SnailfishNumber::Pair(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+/// The steps of reducing a snailfish number, see
+/// [`SnailfishNumber::reduction`].
+pub struct Reduction {
+    number: SnailfishNumber,
+}
+
+impl Reduction {
+    /// Skips the remaining steps and returns the reduced number.
+    pub fn finish(mut self) -> SnailfishNumber {
+        while self.number.reduce_once().is_some() {}
+        self.number
+    }
+}
+
+impl Iterator for Reduction {
+    type Item = (Action, SnailfishNumber);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let action = self.number.reduce_once()?;
+        Some((action, self.number.clone()))
+    }
+}
+
+static INPUT: &str = r#"[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"#;
+
+static INPUT_2: &str = r#"[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
+[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
+[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
+[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
+[7,[5,[[3,8],[1,4]]]]
+[[2,[2,2]],[8,[8,1]]]
+[2,9]
+[1,[[[9,3],9],[[9,0],[0,7]]]]
+[[[5,[7,4]],7],1]
+[[[[4,2],2],6],[8,7]]"#;
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::parse::mangle;
+    use itertools::Itertools;
+
+    use super::{parse, part1, part2, Action, SnailfishNumber, INPUT, INPUT_2};
+
+    fn number(text: &str) -> SnailfishNumber {
+        text.parse().unwrap()
+    }
+
+    fn sum(input: &str) -> String {
+        parse(input)
+            .unwrap()
+            .into_iter()
+            .reduce(|sum, number| sum + number)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(input.len(), 10);
+        assert_eq!(
+            input[6],
+            SnailfishNumber::pair(
+                SnailfishNumber::pair(number("[[5,4],[7,7]]"), SnailfishNumber::Regular(8)),
+                number("[[8,3],8]")
+            )
+        );
+        assert_eq!(
+            input.iter().map(|n| n.to_string()).join("\n"),
+            INPUT.to_string()
+        );
+
+        assert_eq!(
+            "[1,2".parse::<SnailfishNumber>().unwrap_err().to_string(),
+            "day18 line 1, column 5: expected `]`, found end of line"
+        );
+        assert_eq!(
+            "[1;2]".parse::<SnailfishNumber>().unwrap_err().to_string(),
+            "day18 line 1, column 3: expected `,` (found `;`)"
+        );
+        assert_eq!(
+            "[1,2]]".parse::<SnailfishNumber>().unwrap_err().to_string(),
+            "day18 line 1, column 6: expected end of line (found `]`)"
+        );
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day18_explode() {
+        for (before, after) in [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            ),
+            (
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+            ),
+        ] {
+            let (action, number) = number(before).reduction().next().unwrap();
+            assert_eq!(action, Action::Explode);
+            assert_eq!(number.to_string(), after);
+        }
+    }
+
+    #[test]
+    fn day18_reduction() {
+        let sum = SnailfishNumber::pair(number("[[[[4,3],4],4],[7,[[8,4],9]]]"), number("[1,1]"));
+        assert_eq!(sum.to_string(), "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+        assert_eq!(
+            sum.reduction()
+                .map(|(action, number)| (action, number.to_string()))
+                .collect_vec(),
+            vec![
+                (
+                    Action::Explode,
+                    "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]".to_string()
+                ),
+                (
+                    Action::Explode,
+                    "[[[[0,7],4],[15,[0,13]]],[1,1]]".to_string()
+                ),
+                (
+                    Action::Split,
+                    "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]".to_string()
+                ),
+                (
+                    Action::Split,
+                    "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]".to_string()
+                ),
+                (
+                    Action::Explode,
+                    "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn day18_sum() {
+        assert_eq!(
+            sum("[1,1]\n[2,2]\n[3,3]\n[4,4]"),
+            "[[[[1,1],[2,2]],[3,3]],[4,4]]"
+        );
+        assert_eq!(
+            sum("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]"),
+            "[[[[3,0],[5,3]],[4,4]],[5,5]]"
+        );
+        assert_eq!(
+            sum("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]\n[6,6]"),
+            "[[[[5,0],[7,4]],[5,5]],[6,6]]"
+        );
+        assert_eq!(
+            sum(INPUT_2),
+            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"
+        );
+        assert_eq!(
+            sum(INPUT),
+            "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
+        );
+    }
+
+    #[test]
+    fn day18_magnitude() {
+        for (text, magnitude) in [
+            ("[[1,2],[[3,4],5]]", 143),
+            ("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384),
+            ("[[[[1,1],[2,2]],[3,3]],[4,4]]", 445),
+            ("[[[[3,0],[5,3]],[4,4]],[5,5]]", 791),
+            ("[[[[5,0],[7,4]],[5,5]],[6,6]]", 1137),
+            (
+                "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+                3488,
+            ),
+        ] {
+            assert_eq!(number(text).magnitude(), magnitude);
+        }
+    }
+
+    #[test]
+    fn day18_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 4140);
+    }
+
+    #[test]
+    fn day18_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 3993);
+    }
+}
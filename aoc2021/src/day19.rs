@@ -0,0 +1,680 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+};
+
+use aoc::{
+    parse::{blocks, ParseError},
+    solution::{Answer, Example, Solution},
+};
+use itertools::Itertools;
+
+/// How many beacons two scanners need to have in common to be aligned.
+const OVERLAP: usize = 12;
+
+#[aoc_generator(day19)]
+pub fn parse(input: &str) -> Result<Vec<Scanner>, ParseError> {
+    blocks(19, input)
+        .into_iter()
+        .enumerate()
+        .map(|(id, block)| {
+            let header = block[0];
+            let number = header.strip_prefix(header.text, "--- scanner ")?;
+            let number = header.strip_suffix(number, " ---")?;
+            if header.parse::<usize>(number)? != id {
+                return Err(header.error(number, format!("expected scanner {}", id)));
+            }
+            let beacons = block[1..]
+                .iter()
+                .map(|line| {
+                    let (x, rest) = line.split_once(line.text, ",")?;
+                    let (y, z) = line.split_once(rest, ",")?;
+                    Ok([line.parse(x)?, line.parse(y)?, line.parse(z)?])
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Scanner { beacons })
+        })
+        .collect()
+}
+
+#[aoc(day19, part1)]
+pub fn part1(input: &[Scanner]) -> usize {
+    complete_map(input).beacons.len()
+}
+
+#[aoc(day19, part2)]
+pub fn part2(input: &[Scanner]) -> i32 {
+    complete_map(input)
+        .scanners
+        .iter()
+        .flatten()
+        .tuple_combinations()
+        .map(|(a, b)| (0..3).map(|i| (a.position[i] - b.position[i]).abs()).sum())
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+
+    type Input = Vec<Scanner>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(79).part2(3621)]
+    }
+}
+
+pub type Pos3 = [i32; 3];
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Scanner {
+    beacons: Vec<Pos3>,
+}
+
+impl Scanner {
+    /// The squared distances between every two of its beacons, which stay
+    /// the same no matter where the scanner is or how it is turned.
+    fn fingerprint(&self) -> Vec<i32> {
+        self.beacons
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| (0..3).map(|i| (a[i] - b[i]).pow(2)).sum())
+            .sorted()
+            .collect()
+    }
+}
+
+/// A proper rotation by multiples of 90 degrees: axis `i` of the result is
+/// axis `axes[i]` of the input, multiplied by `signs[i]`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Rotation {
+    axes: [usize; 3],
+    signs: [i32; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        axes: [0, 1, 2],
+        signs: [1, 1, 1],
+    };
+
+    /// All 24 orientations a scanner can face. Mirror images are left out:
+    /// an odd permutation of the axes needs an odd number of them flipped.
+    pub fn all() -> Vec<Rotation> {
+        (0..3)
+            .permutations(3)
+            .cartesian_product((0..8).map(|bits| [0, 1, 2].map(|i| 1 - 2 * (bits >> i & 1))))
+            .map(|(axes, signs)| Rotation {
+                axes: [axes[0], axes[1], axes[2]],
+                signs,
+            })
+            .filter(|rotation| rotation.determinant() == 1)
+            .collect()
+    }
+
+    fn determinant(&self) -> i32 {
+        let inversions = (0..3)
+            .tuple_combinations()
+            .filter(|&(i, j)| self.axes[i] > self.axes[j])
+            .count();
+        let parity = if inversions.is_multiple_of(2) { 1 } else { -1 };
+        parity * self.signs.iter().product::<i32>()
+    }
+
+    pub fn apply(&self, pos: Pos3) -> Pos3 {
+        [0, 1, 2].map(|i| self.signs[i] * pos[self.axes[i]])
+    }
+}
+
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let axes = (0..3)
+            .map(|i| {
+                let sign = if self.signs[i] < 0 { '-' } else { '+' };
+                format!("{}{}", sign, ['x', 'y', 'z'][self.axes[i]])
+            })
+            .join(",");
+        write!(f, "({})", axes)
+    }
+}
+
+/// Where a scanner is relative to scanner 0 and how it is turned.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Placement {
+    pub position: Pos3,
+    pub rotation: Rotation,
+    /// The scanner it was aligned with, `None` for scanner 0.
+    pub parent: Option<usize>,
+}
+
+impl Placement {
+    /// A position seen by this scanner, as seen by scanner 0.
+    fn to_global(self, pos: Pos3) -> Pos3 {
+        let rotated = self.rotation.apply(pos);
+        [0, 1, 2].map(|i| self.position[i] + rotated[i])
+    }
+}
+
+/// Every scanner placed relative to scanner 0, as far as possible, and every
+/// beacon they see.
+#[derive(Debug, PartialEq)]
+pub struct BeaconMap {
+    /// `None` for scanners that share too few beacons with any placed one.
+    pub scanners: Vec<Option<Placement>>,
+    pub beacons: HashSet<Pos3>,
+}
+
+impl BeaconMap {
+    /// The scanners that could not be placed.
+    pub fn unplaced(&self) -> Vec<usize> {
+        (0..self.scanners.len())
+            .filter(|&id| self.scanners[id].is_none())
+            .collect()
+    }
+}
+
+impl fmt::Display for BeaconMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (id, placement) in self.scanners.iter().enumerate() {
+            match placement {
+                Some(Placement {
+                    position: [x, y, z],
+                    rotation,
+                    parent,
+                }) => {
+                    write!(f, "scanner {} at {},{},{} facing {}", id, x, y, z, rotation)?;
+                    match parent {
+                        Some(parent) => writeln!(f, ", aligned with scanner {}", parent)?,
+                        None => writeln!(f)?,
+                    }
+                }
+                None => writeln!(f, "scanner {} cannot be placed", id)?,
+            }
+        }
+        write!(f, "{} beacons", self.beacons.len())
+    }
+}
+
+/// Places scanner 0 at the origin and every other scanner by aligning it
+/// with one that is already placed. Each pair of scanners is only tried
+/// once, so scanners without enough overlap stay unplaced.
+pub fn assemble(scanners: &[Scanner]) -> BeaconMap {
+    let mut map = BeaconMap {
+        scanners: vec![None; scanners.len()],
+        beacons: HashSet::new(),
+    };
+    if scanners.is_empty() {
+        return map;
+    }
+
+    let rotations = Rotation::all();
+    let fingerprints = scanners.iter().map(Scanner::fingerprint).collect_vec();
+    let mut global = vec![vec![]; scanners.len()];
+    global[0] = scanners[0].beacons.clone();
+    map.scanners[0] = Some(Placement {
+        position: [0; 3],
+        rotation: Rotation::IDENTITY,
+        parent: None,
+    });
+
+    let mut queue = VecDeque::from([0]);
+    while let Some(placed) = queue.pop_front() {
+        for id in 0..scanners.len() {
+            if map.scanners[id].is_some()
+                || shared(&fingerprints[placed], &fingerprints[id]) < OVERLAP * (OVERLAP - 1) / 2
+            {
+                continue;
+            }
+            if let Some((rotation, position)) = align(&global[placed], &scanners[id], &rotations) {
+                let placement = Placement {
+                    position,
+                    rotation,
+                    parent: Some(placed),
+                };
+                global[id] = scanners[id]
+                    .beacons
+                    .iter()
+                    .map(|&beacon| placement.to_global(beacon))
+                    .collect();
+                map.scanners[id] = Some(placement);
+                queue.push_back(id);
+            }
+        }
+    }
+
+    map.beacons = global.into_iter().flatten().collect();
+    map
+}
+
+/// How many values two sorted lists have in common.
+fn shared(a: &[i32], b: &[i32]) -> usize {
+    let (mut i, mut j, mut count) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                count += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    count
+}
+
+/// The rotation and position that make at least `OVERLAP` of the scanner's
+/// beacons land on `known` beacons.
+fn align(known: &[Pos3], scanner: &Scanner, rotations: &[Rotation]) -> Option<(Rotation, Pos3)> {
+    rotations.iter().find_map(|&rotation| {
+        let mut offsets: HashMap<Pos3, usize> = HashMap::new();
+        for &beacon in &scanner.beacons {
+            let rotated = rotation.apply(beacon);
+            for known in known {
+                let offset = [0, 1, 2].map(|i| known[i] - rotated[i]);
+                let count = offsets.entry(offset).or_insert(0);
+                *count += 1;
+                if *count >= OVERLAP {
+                    return Some((rotation, offset));
+                }
+            }
+        }
+        None
+    })
+}
+
+/// The map of all scanners; panics if any of them cannot be placed, since
+/// neither answer would be right then.
+fn complete_map(input: &[Scanner]) -> BeaconMap {
+    let map = assemble(input);
+    let unplaced = map.unplaced();
+    if !unplaced.is_empty() {
+        panic!(
+            "cannot place scanner{} {}",
+            if unplaced.len() == 1 { "" } else { "s" },
+            unplaced.iter().join(", ")
+        );
+    }
+    map
+}
+
+static INPUT: &str = "\
+--- scanner 0 ---
+404,-588,-901
+528,-643,409
+-838,591,734
+390,-675,-793
+-537,-823,-458
+-485,-357,347
+-345,-311,381
+-661,-816,-575
+-876,649,763
+-618,-824,-621
+553,345,-567
+474,580,667
+-447,-329,318
+-584,868,-557
+544,-627,-890
+564,392,-477
+455,729,728
+-892,524,684
+-689,845,-530
+423,-701,434
+7,-33,-71
+630,319,-379
+443,580,662
+-789,900,-551
+459,-707,401
+
+--- scanner 1 ---
+686,422,578
+605,423,415
+515,917,-361
+-336,658,858
+95,138,22
+-476,619,847
+-340,-569,-846
+567,-361,727
+-460,603,-452
+669,-402,600
+729,430,532
+-500,-761,534
+-322,571,750
+-466,-666,-811
+-429,-592,574
+-355,545,-477
+703,-491,-529
+-328,-685,520
+413,935,-424
+-391,539,-444
+586,-435,557
+-364,-763,-893
+807,-499,-711
+755,-354,-619
+553,889,-390
+
+--- scanner 2 ---
+649,640,665
+682,-795,504
+-784,533,-524
+-644,584,-595
+-588,-843,648
+-30,6,44
+-674,560,763
+500,723,-460
+609,671,-379
+-555,-800,653
+-675,-892,-343
+697,-426,-610
+578,704,681
+493,664,-388
+-671,-858,530
+-667,343,800
+571,-461,-707
+-138,-166,112
+-889,563,-600
+646,-828,498
+640,759,510
+-630,509,768
+-681,-892,-333
+673,-379,-804
+-742,-814,-386
+577,-820,562
+
+--- scanner 3 ---
+-589,542,597
+605,-692,669
+-500,565,-823
+-660,373,557
+-458,-679,-417
+-488,449,543
+-626,468,-788
+338,-750,-386
+528,-832,-391
+562,-778,733
+-938,-730,414
+543,643,-506
+-524,371,-870
+407,773,750
+-104,29,83
+378,-903,-323
+-778,-728,485
+426,699,580
+-438,-605,-362
+-469,-447,-387
+509,732,623
+647,635,-688
+-868,-804,481
+614,-800,639
+595,780,-596
+
+--- scanner 4 ---
+727,592,562
+-293,-554,779
+441,611,-461
+-714,465,-776
+-743,427,-804
+-660,-479,-426
+832,-632,460
+927,-485,-438
+408,393,-506
+466,436,-512
+110,16,151
+-258,-428,682
+-393,719,612
+-211,-452,876
+808,-476,-593
+-575,615,604
+-485,667,467
+-680,325,-822
+-627,-443,-432
+872,-547,-609
+833,512,582
+807,604,487
+839,-516,451
+891,-625,532
+-652,-548,-490
+30,-46,-14";
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::HashSet;
+
+    use aoc::{parse::mangle, rng::Rng};
+    use itertools::Itertools;
+
+    use super::{assemble, parse, part1, part2, Placement, Pos3, Rotation, Scanner, INPUT};
+    use crate::gen;
+
+    static STUB: &str = r#"--- scanner 0 ---
+404,-588,-901
+528,-643,409
+
+--- scanner 1 ---
+686,422,578
+605,423,415
+515,917,-361"#;
+
+    const RANGE: i32 = 1000;
+
+    fn inverse(rotation: Rotation) -> Rotation {
+        let mut inverse = Rotation::IDENTITY;
+        for i in 0..3 {
+            inverse.axes[rotation.axes[i]] = i;
+            inverse.signs[rotation.axes[i]] = rotation.signs[i];
+        }
+        inverse
+    }
+
+    fn random_in(rng: &mut Rng, low: Pos3, high: Pos3) -> Pos3 {
+        [0, 1, 2].map(|i| rng.range(low[i] as i64..=high[i] as i64) as i32)
+    }
+
+    /// What a scanner at `position`, turned by `rotation`, reports of the
+    /// beacons in `world`.
+    fn observe(world: &HashSet<Pos3>, position: Pos3, rotation: Rotation) -> Scanner {
+        let beacons = world
+            .iter()
+            .map(|beacon| [0, 1, 2].map(|i| beacon[i] - position[i]))
+            .filter(|relative| relative.iter().all(|d| d.abs() <= RANGE))
+            .map(|relative| inverse(rotation).apply(relative))
+            .sorted()
+            .collect();
+        Scanner { beacons }
+    }
+
+    /// Scanners at `positions`, turned by the rotation with the matching
+    /// index, that see 14 beacons in common with each of their `links` and
+    /// ten more of their own.
+    fn scenario(
+        positions: &[Pos3],
+        rotations: &[usize],
+        links: &[(usize, usize)],
+    ) -> (Vec<Scanner>, HashSet<Pos3>) {
+        let mut rng = Rng::new(19);
+        let mut world = HashSet::new();
+        for &(a, b) in links {
+            let (a, b) = (positions[a], positions[b]);
+            let low = [0, 1, 2].map(|i| a[i].max(b[i]) - RANGE);
+            let high = [0, 1, 2].map(|i| a[i].min(b[i]) + RANGE);
+            for _ in 0..14 {
+                world.insert(random_in(&mut rng, low, high));
+            }
+        }
+        for position in positions {
+            let low = position.map(|p| p - RANGE);
+            let high = position.map(|p| p + RANGE);
+            for _ in 0..10 {
+                world.insert(random_in(&mut rng, low, high));
+            }
+        }
+        let all = Rotation::all();
+        let scanners = positions
+            .iter()
+            .zip(rotations)
+            .map(|(&position, &rotation)| observe(&world, position, all[rotation]))
+            .collect();
+        (scanners, world)
+    }
+
+    const POSITIONS: [Pos3; 4] = [
+        [0, 0, 0],
+        [1105, -80, 233],
+        [2150, 1050, -120],
+        [-960, 300, 810],
+    ];
+
+    #[test]
+    fn generator() {
+        let input = parse(STUB).unwrap();
+        assert_eq!(
+            input,
+            vec![
+                Scanner {
+                    beacons: vec![[404, -588, -901], [528, -643, 409]]
+                },
+                Scanner {
+                    beacons: vec![[686, 422, 578], [605, 423, 415], [515, 917, -361]]
+                },
+            ]
+        );
+        assert_eq!(
+            parse("--- scanner 1 ---\n1,2,3").unwrap_err().to_string(),
+            "day19 line 1, column 13: expected scanner 0 (found `1`)"
+        );
+        assert_eq!(
+            parse("--- scanner 0 --\n1,2,3").unwrap_err().to_string(),
+            "day19 line 1, column 13: expected ` ---` (found `0 --`)"
+        );
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day19_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 79);
+    }
+
+    #[test]
+    fn day19_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 3621);
+    }
+
+    #[test]
+    fn day19_rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations.len(), 24);
+        assert_eq!(
+            rotations
+                .iter()
+                .map(|r| r.apply([1, 2, 3]))
+                .unique()
+                .count(),
+            24
+        );
+        let cross = |a: Pos3, b: Pos3| {
+            [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ]
+        };
+        for rotation in rotations {
+            // Proper rotations keep the axes right-handed.
+            assert_eq!(
+                cross(rotation.apply([1, 0, 0]), rotation.apply([0, 1, 0])),
+                rotation.apply([0, 0, 1])
+            );
+            assert_eq!(
+                inverse(rotation).apply(rotation.apply([4, -5, 6])),
+                [4, -5, 6]
+            );
+        }
+    }
+
+    #[test]
+    fn day19_assemble() {
+        let rotations = [0, 7, 13, 22];
+        let (scanners, world) = scenario(&POSITIONS, &rotations, &[(0, 1), (1, 2), (0, 3)]);
+        let map = assemble(&scanners);
+
+        let all = Rotation::all();
+        assert_eq!(
+            map.scanners,
+            vec![
+                Some(Placement {
+                    position: POSITIONS[0],
+                    rotation: Rotation::IDENTITY,
+                    parent: None
+                }),
+                Some(Placement {
+                    position: POSITIONS[1],
+                    rotation: all[rotations[1]],
+                    parent: Some(0)
+                }),
+                Some(Placement {
+                    position: POSITIONS[2],
+                    rotation: all[rotations[2]],
+                    parent: Some(1)
+                }),
+                Some(Placement {
+                    position: POSITIONS[3],
+                    rotation: all[rotations[3]],
+                    parent: Some(0)
+                }),
+            ]
+        );
+        assert_eq!(map.beacons, world);
+        assert!(map.unplaced().is_empty());
+        assert!(map
+            .to_string()
+            .starts_with("scanner 0 at 0,0,0 facing (+x,+y,+z)\n"));
+        assert_eq!(part1(&scanners), world.len());
+        assert_eq!(part2(&scanners), 3110 + 750 + 930);
+    }
+
+    #[test]
+    fn day19_unplaced() {
+        let mut positions = POSITIONS.to_vec();
+        positions.push([5000, 5000, 5000]);
+        let (scanners, _) = scenario(&positions, &[0, 1, 2, 3, 4], &[(0, 1), (1, 2)]);
+        let map = assemble(&scanners);
+        assert_eq!(map.unplaced(), vec![3, 4]);
+        assert!(map.to_string().contains("scanner 4 cannot be placed"));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot place scanners 3, 4")]
+    fn day19_part1_unplaced() {
+        let mut positions = POSITIONS.to_vec();
+        positions.push([5000, 5000, 5000]);
+        let (scanners, _) = scenario(&positions, &[0, 1, 2, 3, 4], &[(0, 1), (1, 2)]);
+        part1(&scanners);
+    }
+
+    #[test]
+    fn day19_random() {
+        let scanners = parse(&gen::input(19, 1, 8).unwrap()).unwrap();
+        assert!(assemble(&scanners).unplaced().is_empty());
+    }
+}
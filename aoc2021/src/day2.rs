@@ -0,0 +1,759 @@
+use std::{fmt, str::FromStr};
+
+use aoc::{
+    parse::{lines, Line, ParseError},
+    render::Color,
+    solution::{Answer, Example, Solution},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+/// Parses `text`, a command on `line` such as `forward 5`.
+fn parse_command<'a>(line: &Line<'a>, text: &'a str) -> Result<Command, ParseError> {
+    let (direction, value) = line.split_once(text, " ")?;
+    let command = match direction {
+        "forward" => Command::Forward,
+        "down" => Command::Down,
+        "up" => Command::Up,
+        _ => return Err(line.error(direction, "expected `forward`, `down` or `up`")),
+    };
+    Ok(command(line.parse(value)?))
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line {
+            day: 2,
+            number: 1,
+            text: s,
+        };
+        parse_command(&line, s)
+    }
+}
+
+/// A course written in a small language on top of the plain commands.
+///
+/// Besides `forward N`, `down N` and `up N`, a program may contain
+/// `repeat N { ... }` blocks and macros, defined at the top level with
+/// `def name { ... }` and used by name after that. Anything after `#` on a
+/// line is a comment. Commands and braces are separated by whitespace,
+/// including line breaks, so a block fits on one line or spans several, but
+/// a command is written like in the puzzle: its keyword, one space and its
+/// argument.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Program {
+    macros: Vec<(String, Vec<Item>)>,
+    body: Vec<Item>,
+}
+
+/// A statement that expands to at least one command: repeats and calls that
+/// would expand to nothing are left out when parsing.
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Item {
+    Command(Command),
+    Repeat(usize, Vec<Item>),
+    Call(usize),
+}
+
+impl Program {
+    /// The commands the program expands to, produced as they are needed.
+    pub fn commands(&self) -> Commands<'_> {
+        Commands {
+            macros: &self.macros,
+            stack: vec![Frame {
+                items: &self.body,
+                next: 0,
+                passes: 1,
+            }],
+        }
+    }
+}
+
+/// See [`Program::commands`].
+pub struct Commands<'a> {
+    macros: &'a [(String, Vec<Item>)],
+    stack: Vec<Frame<'a>>,
+}
+
+/// A block being expanded, with the number of passes over it that are left.
+struct Frame<'a> {
+    items: &'a [Item],
+    next: usize,
+    passes: usize,
+}
+
+impl Iterator for Commands<'_> {
+    type Item = Command;
+
+    fn next(&mut self) -> Option<Command> {
+        loop {
+            let frame = self.stack.last_mut()?;
+            let Some(item) = frame.items.get(frame.next) else {
+                if frame.passes > 1 {
+                    frame.passes -= 1;
+                    frame.next = 0;
+                } else {
+                    self.stack.pop();
+                }
+                continue;
+            };
+            frame.next += 1;
+            let (items, passes) = match item {
+                Item::Command(command) => return Some(*command),
+                Item::Repeat(times, body) => (body, *times),
+                Item::Call(index) => (&self.macros[*index].1, 1),
+            };
+            self.stack.push(Frame {
+                items,
+                next: 0,
+                passes,
+            });
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Token<'a> {
+    line: Line<'a>,
+    text: &'a str,
+}
+
+/// Splits the lines into words and braces, dropping comments.
+fn tokenize(input: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    for line in lines(2, input) {
+        let text = line.text.split('#').next().unwrap();
+        let mut rest = text;
+        while let Some(start) = rest.find(|c: char| !c.is_whitespace()) {
+            rest = &rest[start..];
+            let end = match rest.find(|c: char| c.is_whitespace() || c == '{' || c == '}') {
+                Some(0) => 1,
+                Some(end) => end,
+                None => rest.len(),
+            };
+            tokens.push(Token {
+                line,
+                text: &rest[..end],
+            });
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+const KEYWORDS: [&str; 5] = ["forward", "down", "up", "repeat", "def"];
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: std::vec::IntoIter<Token<'a>>,
+    macros: Vec<(String, Vec<Item>)>,
+}
+
+impl<'a> Parser<'a> {
+    fn next(&mut self, what: &str) -> Result<Token<'a>, ParseError> {
+        self.tokens
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(2, self.input, what))
+    }
+
+    fn number<T: FromStr>(&mut self, what: &str) -> Result<T, ParseError> {
+        let token = self.next(what)?;
+        token.line.parse(token.text)
+    }
+
+    fn open(&mut self) -> Result<Token<'a>, ParseError> {
+        let token = self.next("`{`")?;
+        match token.text {
+            "{" => Ok(token),
+            _ => Err(token.line.error(token.text, "expected `{`")),
+        }
+    }
+
+    /// The items up to the `}` matching `open`, or up to the end of the input
+    /// at the top level.
+    fn block(&mut self, open: Option<Token>) -> Result<Vec<Item>, ParseError> {
+        let mut items = vec![];
+        loop {
+            let Some(token) = self.tokens.next() else {
+                return match open {
+                    Some(open) => Err(open.line.error(open.text, "unclosed `{`")),
+                    None => Ok(items),
+                };
+            };
+            let line = token.line;
+            match token.text {
+                "}" if open.is_some() => return Ok(items),
+                "{" | "}" => {
+                    return Err(line.error(token.text, format!("unexpected `{}`", token.text)))
+                }
+                "forward" | "down" | "up" => {
+                    // The argument has to be on the same line as the keyword.
+                    let mut text = token.text;
+                    if let Some(argument) = self.tokens.as_slice().first() {
+                        if argument.line.number == line.number {
+                            text = span(line.text, token.text, argument.text);
+                            self.tokens.next();
+                        }
+                    }
+                    items.push(Item::Command(parse_command(&line, text)?));
+                }
+                "repeat" => {
+                    let times = self.number("a repeat count")?;
+                    let open = self.open()?;
+                    let body = self.block(Some(open))?;
+                    if times > 0 && !body.is_empty() {
+                        items.push(Item::Repeat(times, body));
+                    }
+                }
+                "def" if open.is_some() => {
+                    return Err(
+                        line.error(token.text, "macros can only be defined at the top level")
+                    );
+                }
+                "def" => {
+                    let name = self.next("a macro name")?;
+                    if !is_name(name.text) {
+                        return Err(name.line.error(name.text, "expected a macro name"));
+                    }
+                    if self.macros.iter().any(|(defined, _)| defined == name.text) {
+                        return Err(name.line.error(name.text, "macro already defined"));
+                    }
+                    let open = self.open()?;
+                    let body = self.block(Some(open))?;
+                    self.macros.push((name.text.to_string(), body));
+                }
+                text => match self.macros.iter().position(|(name, _)| name == text) {
+                    Some(index) if self.macros[index].1.is_empty() => {}
+                    Some(index) => items.push(Item::Call(index)),
+                    None => {
+                        return Err(line.error(
+                            text,
+                            "expected `forward`, `down`, `up`, `repeat`, `def` or a macro",
+                        ))
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// The part of `line` from the start of `first` to the end of `last`, both
+/// slices of it.
+fn span<'a>(line: &'a str, first: &str, last: &str) -> &'a str {
+    let start = first.as_ptr() as usize - line.as_ptr() as usize;
+    let end = last.as_ptr() as usize + last.len() - line.as_ptr() as usize;
+    &line[start..end]
+}
+
+fn is_name(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && text.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.contains(&text)
+}
+
+#[aoc_generator(day2)]
+pub fn parse(input: &str) -> Result<Program, ParseError> {
+    let mut parser = Parser {
+        input,
+        tokens: tokenize(input).into_iter(),
+        macros: vec![],
+    };
+    let body = parser.block(None)?;
+    Ok(Program {
+        macros: parser.macros,
+        body,
+    })
+}
+
+#[aoc(day2, part1)]
+pub fn part1(input: &Program) -> Result<i64, String> {
+    answer(Direct::default(), input)
+}
+
+#[aoc(day2, part2)]
+pub fn part2(input: &Program) -> Result<i64, String> {
+    answer(Aimed::default(), input)
+}
+
+fn answer(submarine: impl Submarine, input: &Program) -> Result<i64, String> {
+    follow(submarine, input.commands())
+        .and_then(|position| position.product())
+        .map_err(|err| err.to_string())
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        part2(input).map(Answer::from)
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(150).part2(900)]
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct Position {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl Position {
+    /// The horizontal position multiplied by the depth, the puzzle's answer.
+    pub fn product(&self) -> Result<i64, Overflow> {
+        self.horizontal.checked_mul(self.depth).ok_or(Overflow)
+    }
+}
+
+/// A position or answer too large for an `i64`, which a long enough program
+/// can reach.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the submarine's position overflows")
+    }
+}
+
+/// Adds `value` to `coordinate`, leaving it unchanged on overflow.
+fn add(coordinate: &mut i64, value: i64) -> Result<(), Overflow> {
+    *coordinate = coordinate.checked_add(value).ok_or(Overflow)?;
+    Ok(())
+}
+
+/// A way of interpreting commands.
+pub trait Submarine {
+    fn position(&self) -> Position;
+
+    fn execute(&mut self, command: Command) -> Result<(), Overflow>;
+}
+
+/// Part 1: `down` and `up` change the depth directly, and the aim stays 0.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Direct {
+    position: Position,
+}
+
+impl Submarine for Direct {
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn execute(&mut self, command: Command) -> Result<(), Overflow> {
+        match command {
+            Command::Forward(value) => add(&mut self.position.horizontal, value.into()),
+            Command::Down(value) => add(&mut self.position.depth, value.into()),
+            Command::Up(value) => add(&mut self.position.depth, -i64::from(value)),
+        }
+    }
+}
+
+/// Part 2: `down` and `up` change the aim, and moving forward dives along it.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Aimed {
+    position: Position,
+}
+
+impl Submarine for Aimed {
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn execute(&mut self, command: Command) -> Result<(), Overflow> {
+        match command {
+            Command::Forward(value) => {
+                let dive = self
+                    .position
+                    .aim
+                    .checked_mul(value.into())
+                    .ok_or(Overflow)?;
+                let mut position = self.position;
+                add(&mut position.horizontal, value.into())?;
+                add(&mut position.depth, dive)?;
+                self.position = position;
+                Ok(())
+            }
+            Command::Down(value) => add(&mut self.position.aim, value.into()),
+            Command::Up(value) => add(&mut self.position.aim, -i64::from(value)),
+        }
+    }
+}
+
+/// Where the submarine ends up after executing all the commands.
+pub fn follow(
+    mut submarine: impl Submarine,
+    commands: impl IntoIterator<Item = Command>,
+) -> Result<Position, Overflow> {
+    for command in commands {
+        submarine.execute(command)?;
+    }
+    Ok(submarine.position())
+}
+
+/// Every position a submarine passes through, from where it starts to where
+/// it ends up. Step `i` is the position after the `i`th command, so step 0 is
+/// the start.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Course {
+    pub positions: Vec<Position>,
+}
+
+impl Course {
+    /// Follows the commands, recording the position after each one.
+    pub fn record(
+        mut submarine: impl Submarine,
+        commands: impl IntoIterator<Item = Command>,
+    ) -> Result<Self, Overflow> {
+        let mut positions = vec![submarine.position()];
+        for command in commands {
+            submarine.execute(command)?;
+            positions.push(submarine.position());
+        }
+        Ok(Course { positions })
+    }
+
+    pub fn end(&self) -> Position {
+        self.positions[self.positions.len() - 1]
+    }
+
+    /// The greatest depth reached and the first step it was reached at.
+    pub fn max_depth(&self) -> (usize, i64) {
+        let (step, position) = self
+            .positions
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, position)| position.depth)
+            .unwrap();
+        (step, position.depth)
+    }
+
+    /// One row per step, after a `step,horizontal,depth,aim` header.
+    pub fn csv(&self) -> String {
+        let mut csv = "step,horizontal,depth,aim".to_string();
+        for (step, position) in self.positions.iter().enumerate() {
+            csv += &format!(
+                "\n{},{},{},{}",
+                step, position.horizontal, position.depth, position.aim
+            );
+        }
+        csv + "\n"
+    }
+
+    /// The course seen from the side as a polyline, with depth increasing
+    /// downwards. Depths usually dwarf the distance travelled, so the drawing
+    /// is stretched to fill `width` by `height` pixels.
+    pub fn svg(&self, width: usize, height: usize) -> String {
+        let (mut left, mut right, mut top, mut bottom) = (0, 0, 0, 0);
+        for position in &self.positions {
+            left = left.min(position.horizontal);
+            right = right.max(position.horizontal);
+            top = top.min(position.depth);
+            bottom = bottom.max(position.depth);
+        }
+        let points = self
+            .positions
+            .iter()
+            .map(|position| format!("{},{}", position.horizontal, position.depth))
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"{} {} {} {}\" preserveAspectRatio=\"none\">\n\
+             <polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"2\" vector-effect=\"non-scaling-stroke\"/>\n\
+             </svg>\n",
+            width,
+            height,
+            left,
+            top,
+            right.abs_diff(left).max(1),
+            bottom.abs_diff(top).max(1),
+            points,
+            Color::RED
+        )
+    }
+}
+
+static INPUT: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+
+#[cfg(test)]
+pub mod tests {
+    use super::{
+        follow, parse, part1, part2, Aimed, Command, Course, Direct, Overflow, Position, INPUT,
+    };
+    use aoc::parse::{mangle, ParseError};
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(
+            input.commands().collect::<Vec<_>>(),
+            [
+                Command::Forward(5),
+                Command::Down(5),
+                Command::Forward(8),
+                Command::Up(3),
+                Command::Down(8),
+                Command::Forward(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn generator_error() {
+        assert_eq!(
+            parse("forward 5\nbackward 5"),
+            Err(ParseError::new(
+                2,
+                2,
+                1,
+                "backward",
+                "expected `forward`, `down`, `up`, `repeat`, `def` or a macro"
+            ))
+        );
+        assert_eq!(
+            parse("forward 5\ndown five").unwrap_err(),
+            ParseError::new(2, 2, 6, "five", "expected i32")
+        );
+        assert_eq!("up 3".parse(), Ok(Command::Up(3)));
+        assert_eq!(
+            "up".parse::<Command>(),
+            Err(ParseError::new(2, 1, 1, "up", "expected ` `"))
+        );
+    }
+
+    #[test]
+    fn day2_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), Ok(150));
+    }
+
+    #[test]
+    fn day2_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), Ok(900));
+    }
+
+    #[test]
+    fn day2_submarines() {
+        let commands = [Command::Down(2), Command::Forward(3), Command::Up(5)];
+        assert_eq!(
+            follow(Direct::default(), commands),
+            Ok(Position {
+                horizontal: 3,
+                depth: -3,
+                aim: 0
+            })
+        );
+        assert_eq!(
+            follow(Aimed::default(), commands),
+            Ok(Position {
+                horizontal: 3,
+                depth: 6,
+                aim: -3
+            })
+        );
+    }
+
+    #[test]
+    fn day2_overflow() {
+        // These answers no longer fit in an i32, but do in an i64.
+        let input = parse("down 100000\nforward 100000").unwrap();
+        assert_eq!(part1(&input), Ok(10_000_000_000));
+        let input = parse("down 1\nrepeat 100000 { forward 10000 }").unwrap();
+        assert_eq!(part2(&input), Ok(1_000_000_000_000_000_000));
+
+        let input = parse("down 1\nrepeat 100000 { forward 100000 }").unwrap();
+        assert_eq!(
+            part2(&input),
+            Err("the submarine's position overflows".to_string())
+        );
+        let input = parse("repeat 2 { forward 2147483647 down 2147483647 }").unwrap();
+        assert_eq!(part1(&input), Err(Overflow.to_string()));
+        let input = parse("down 2147483647\nrepeat 3 { forward 2147483647 }").unwrap();
+        assert_eq!(follow(Aimed::default(), input.commands()), Err(Overflow));
+    }
+
+    #[test]
+    fn day2_course() {
+        let input = parse(INPUT).unwrap();
+        let course = Course::record(Aimed::default(), input.commands()).unwrap();
+        assert_eq!(course.positions.len(), 7);
+        assert_eq!(course.positions[0], Position::default());
+        assert_eq!(Ok(course.end()), follow(Aimed::default(), input.commands()));
+        assert_eq!(course.max_depth(), (6, 60));
+
+        let course = Course::record(Direct::default(), input.commands()).unwrap();
+        assert_eq!(course.max_depth(), (5, 10));
+        let course = Course::record(Direct::default(), []).unwrap();
+        assert_eq!(course.max_depth(), (0, 0));
+    }
+
+    #[test]
+    fn day2_course_export() {
+        let commands = [Command::Forward(2), Command::Down(1), Command::Forward(3)];
+        let course = Course::record(Aimed::default(), commands).unwrap();
+        assert_eq!(
+            course.csv(),
+            "step,horizontal,depth,aim\n0,0,0,0\n1,2,0,0\n2,2,0,1\n3,5,3,1\n"
+        );
+
+        let svg = course.svg(400, 300);
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains("width=\"400\" height=\"300\" viewBox=\"0 0 5 3\""));
+        assert!(svg.contains("<polyline points=\"0,0 2,0 2,0 5,3\""));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn day2_language() {
+        let program = parse(
+            "\
+# Out to the first buoy.
+forward 5
+
+def dive { down 2 forward 1 }   # a short dive
+def zigzag {
+    repeat 2 { dive up 1 }
+}
+
+repeat 3 {
+    zigzag
+}
+forward 4",
+        )
+        .unwrap();
+        let zigzag = [
+            Command::Down(2),
+            Command::Forward(1),
+            Command::Up(1),
+            Command::Down(2),
+            Command::Forward(1),
+            Command::Up(1),
+        ];
+        let mut expected = vec![Command::Forward(5)];
+        for _ in 0..3 {
+            expected.extend(zigzag);
+        }
+        expected.push(Command::Forward(4));
+        assert_eq!(program.commands().collect::<Vec<_>>(), expected);
+
+        // Braces need no spaces around them.
+        assert_eq!(
+            parse("repeat 2{up 1}")
+                .unwrap()
+                .commands()
+                .collect::<Vec<_>>(),
+            [Command::Up(1), Command::Up(1)]
+        );
+        assert_eq!(parse("# nothing\n\n").unwrap().commands().next(), None);
+    }
+
+    #[test]
+    fn day2_language_lazy() {
+        let program =
+            parse("repeat 1000000000 { repeat 1000000000 { forward 1 down 2 } }").unwrap();
+        assert_eq!(
+            program.commands().take(3).collect::<Vec<_>>(),
+            [Command::Forward(1), Command::Down(2), Command::Forward(1)]
+        );
+
+        // Blocks that expand to nothing are never entered, however often they
+        // are repeated.
+        let program =
+            parse("def nothing { repeat 5 { } }\nrepeat 1000000000000 { nothing repeat 0 { up 1 } }\nup 2")
+                .unwrap();
+        assert_eq!(program.commands().collect::<Vec<_>>(), [Command::Up(2)]);
+    }
+
+    #[test]
+    fn day2_language_errors() {
+        let error = |input| parse(input).unwrap_err();
+        assert_eq!(
+            error("forward 1\nrepeat 2 {\n  up 1\n# }"),
+            ParseError::new(2, 2, 10, "{", "unclosed `{`")
+        );
+        assert_eq!(
+            error("up 1 }"),
+            ParseError::new(2, 1, 6, "}", "unexpected `}`")
+        );
+        assert_eq!(
+            error("dive\ndef dive { down 1 }"),
+            ParseError::new(
+                2,
+                1,
+                1,
+                "dive",
+                "expected `forward`, `down`, `up`, `repeat`, `def` or a macro"
+            )
+        );
+        assert_eq!(
+            error("repeat 2 {\n  def dive { down 1 }\n}"),
+            ParseError::new(
+                2,
+                2,
+                3,
+                "def",
+                "macros can only be defined at the top level"
+            )
+        );
+        assert_eq!(
+            error("def a { up 1 }\ndef a { up 2 }"),
+            ParseError::new(2, 2, 5, "a", "macro already defined")
+        );
+        assert_eq!(
+            error("def up { down 1 }"),
+            ParseError::new(2, 1, 5, "up", "expected a macro name")
+        );
+        assert_eq!(
+            error("repeat -1 { up 1 }"),
+            ParseError::new(2, 1, 8, "-1", "expected usize")
+        );
+        assert_eq!(
+            error("repeat 2 up 1"),
+            ParseError::new(2, 1, 10, "up", "expected `{`")
+        );
+        assert_eq!(
+            error("forward 1\ndown\n\n"),
+            ParseError::new(2, 2, 1, "down", "expected ` `")
+        );
+        assert_eq!(
+            error("forward\n5"),
+            ParseError::new(2, 1, 1, "forward", "expected ` `")
+        );
+        assert_eq!(
+            error("repeat 2 { up }"),
+            ParseError::new(2, 1, 15, "}", "expected i32")
+        );
+        assert_eq!(
+            error("up  1"),
+            ParseError::new(2, 1, 4, " 1", "expected i32")
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use std::fmt;
+
+use aoc::{
+    grid::Grid,
+    parse::{blocks, Line, ParseError},
+    render::{Color, Image},
+    solution::{Answer, Example, Solution},
+};
+
+#[aoc_generator(day20)]
+pub fn parse(input: &str) -> Result<TrenchMap, ParseError> {
+    let blocks = blocks(20, input);
+    let (algorithm, image) = match blocks.as_slice() {
+        [algorithm, image] => (algorithm, image),
+        [_] => return Err(ParseError::end_of_input(20, input, "an image")),
+        [] => return Err(ParseError::end_of_input(20, input, "an algorithm")),
+        [_, _, extra, ..] => return Err(extra[0].error(extra[0].text, "expected end of input")),
+    };
+
+    let last = algorithm[algorithm.len() - 1];
+    let algorithm = algorithm
+        .iter()
+        .map(pixels)
+        .collect::<Result<Vec<_>, _>>()?
+        .concat();
+    if algorithm.len() != 512 {
+        return Err(last.error(
+            last.text,
+            format!(
+                "expected 512 pixels in the algorithm, found {}",
+                algorithm.len()
+            ),
+        ));
+    }
+
+    let width = image[0].text.chars().count();
+    let mut cells = vec![];
+    for line in image {
+        let row = pixels(line)?;
+        if row.len() != width {
+            return Err(line.error(line.text, format!("expected {} pixels", width)));
+        }
+        cells.extend(row);
+    }
+
+    Ok(TrenchMap {
+        algorithm,
+        image: Picture {
+            pixels: Grid::new(width, image.len(), cells),
+            background: false,
+        },
+    })
+}
+
+#[aoc(day20, part1)]
+pub fn part1(input: &TrenchMap) -> usize {
+    lit_after(input, 2)
+}
+
+#[aoc(day20, part2)]
+pub fn part2(input: &TrenchMap) -> usize {
+    lit_after(input, 50)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+
+    type Input = TrenchMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(35).part2(3351)]
+    }
+
+    /// The image after `step` passes, 50 by default.
+    fn render(input: &Self::Input, step: Option<usize>) -> Option<Image> {
+        let picture = input.enhancements().nth(step.unwrap_or(50)).unwrap();
+        Some(picture.pixels.map(|&lit| match lit {
+            true => Color::WHITE,
+            false => Color::BLACK,
+        }))
+    }
+}
+
+fn pixels(line: &Line) -> Result<Vec<bool>, ParseError> {
+    line.chars()
+        .map(|(c, token)| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(line.error(token, "expected `#` or `.`")),
+        })
+        .collect()
+}
+
+fn lit_after(input: &TrenchMap, passes: usize) -> usize {
+    input
+        .enhancements()
+        .nth(passes)
+        .unwrap()
+        .lit()
+        .unwrap_or_else(|| panic!("infinitely many pixels are lit after {} passes", passes))
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TrenchMap {
+    algorithm: Vec<bool>,
+    image: Picture,
+}
+
+impl TrenchMap {
+    /// The input image followed by the image after every further pass,
+    /// without end.
+    pub fn enhancements(&self) -> impl Iterator<Item = Picture> + '_ {
+        std::iter::successors(Some(self.image.clone()), |picture| {
+            Some(picture.enhance(&self.algorithm))
+        })
+    }
+}
+
+/// A finite window onto an infinite image: every pixel outside of it has
+/// the colour of the background.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Picture {
+    pixels: Grid<bool>,
+    background: bool,
+}
+
+impl Picture {
+    /// The number of lit pixels, or `None` if the background is lit and
+    /// there are infinitely many.
+    pub fn lit(&self) -> Option<usize> {
+        (!self.background).then(|| self.pixels.values().filter(|&&lit| lit).count())
+    }
+
+    /// Whether the pixel at `row`, `col` of the window is lit, looking past
+    /// its edges into the background.
+    fn get(&self, row: isize, col: isize) -> bool {
+        match (usize::try_from(row), usize::try_from(col)) {
+            (Ok(row), Ok(col)) => *self.pixels.get((row, col)).unwrap_or(&self.background),
+            _ => self.background,
+        }
+    }
+
+    /// One pass of the algorithm. Only the window and the ring of pixels
+    /// around it can change; everything further out sees nothing but
+    /// background, so the whole background turns into the pixel the
+    /// algorithm gives for nine background pixels.
+    fn enhance(&self, algorithm: &[bool]) -> Picture {
+        let pixels = Grid::from_fn(
+            self.pixels.width() + 2,
+            self.pixels.height() + 2,
+            |(r, c)| {
+                let index = (-1..=1)
+                    .flat_map(|dr| (-1..=1).map(move |dc| (dr, dc)))
+                    .fold(0, |index, (dr, dc)| {
+                        let lit = self.get(r as isize - 1 + dr, c as isize - 1 + dc);
+                        index << 1 | lit as usize
+                    });
+                algorithm[index]
+            },
+        );
+        let background = match self.background {
+            true => algorithm[511],
+            false => algorithm[0],
+        };
+        Picture { pixels, background }
+    }
+}
+
+impl fmt::Display for Picture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = self.pixels.map(|&lit| if lit { '#' } else { '.' });
+        write!(f, "{}", text)
+    }
+}
+
+static INPUT: &str = r#"..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###"#;
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::parse::mangle;
+
+    use super::{parse, part1, part2, INPUT};
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(input.algorithm.len(), 512);
+        assert!(input.algorithm[2] && !input.algorithm[0]);
+        assert_eq!(input.image.to_string(), "#..#.\n#....\n##..#\n..#..\n..###");
+        assert_eq!(
+            parse("#.#\n\n#..").unwrap_err().to_string(),
+            "day20 line 1, column 1: expected 512 pixels in the algorithm, found 3 (found `#.#`)"
+        );
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day20_enhancements() {
+        let input = parse(INPUT).unwrap();
+        let passes = input.enhancements().take(3).collect::<Vec<_>>();
+        assert_eq!(
+            passes[1].to_string(),
+            "\
+.##.##.
+#..#.#.
+##.#..#
+####..#
+.#..##.
+..##..#
+...#.#."
+        );
+        assert_eq!(
+            passes
+                .iter()
+                .map(|picture| picture.lit())
+                .collect::<Vec<_>>(),
+            vec![Some(10), Some(24), Some(35)]
+        );
+    }
+
+    #[test]
+    fn day20_background() {
+        // Nine dark pixels light up and nine lit pixels go dark, so the
+        // background flips with every pass while the single lit pixel comes
+        // back on every other pass.
+        let algorithm = format!("#{}.", ".".repeat(510));
+        let input = parse(&format!("{}\n\n...\n.#.\n...", algorithm)).unwrap();
+        let lit = input.enhancements().take(5).map(|picture| picture.lit());
+        assert_eq!(
+            lit.collect::<Vec<_>>(),
+            vec![Some(1), None, Some(1), None, Some(1)]
+        );
+    }
+
+    #[test]
+    fn day20_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 35);
+    }
+
+    #[test]
+    fn day20_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 3351);
+    }
+}
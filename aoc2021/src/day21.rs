@@ -1,17 +1,34 @@
-use itertools::Itertools;
 use std::collections::HashMap;
 
+use aoc::{
+    parse::{lines, ParseError},
+    solution::{Answer, Example, Solution},
+};
+
 #[aoc_generator(day21)]
-fn generator_input(input: &str) -> (u32, u32) {
-    input
-        .lines()
-        .map(|a| a.chars().last().unwrap().to_digit(10).unwrap())
-        .collect_tuple()
-        .unwrap()
+pub fn parse(input: &str) -> Result<(u32, u32), ParseError> {
+    let mut positions = lines(21, input).map(|line| {
+        let (_, position) = line.split_once(line.text, "starting position: ")?;
+        match line.parse(position)? {
+            p @ 1..=10 => Ok(p),
+            _ => Err(line.error(position, "expected a position between 1 and 10")),
+        }
+    });
+    let mut next = |player| {
+        positions.next().unwrap_or_else(|| {
+            Err(ParseError::end_of_input(
+                21,
+                input,
+                &format!("player {}", player),
+            ))
+        })
+    };
+
+    Ok((next(1)?, next(2)?))
 }
 
 #[aoc(day21, part1)]
-fn part1(input: &(u32, u32)) -> u64 {
+pub fn part1(input: &(u32, u32)) -> u64 {
     let mut die = DeterministicDie::new();
     let (mut p1_pos, mut p2_pos) = (input.0 as u64, input.1 as u64);
     let (mut p1_score, mut p2_score) = (0, 0);
@@ -32,7 +49,7 @@ fn part1(input: &(u32, u32)) -> u64 {
 }
 
 #[aoc(day21, part2)]
-fn part2(input: &(u32, u32)) -> u64 {
+pub fn part2(input: &(u32, u32)) -> u64 {
     let quantum_rolls = get_quantum_rolls();
     let (p1_wins, p2_wins) = game_turn(
         input.0 as u64,
@@ -46,6 +63,30 @@ fn part2(input: &(u32, u32)) -> u64 {
     p1_wins.max(p2_wins)
 }
 
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+
+    type Input = (u32, u32);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(739785).part2(444356092776315i64)]
+    }
+}
+
 fn move_pawn(pos: u64, steps: u64) -> u64 {
     (pos + steps - 1) % 10 + 1
 }
@@ -149,27 +190,34 @@ enum Player {
     P2,
 }
 
+static INPUT: &str = "Player 1 starting position: 4\nPlayer 2 starting position: 8";
+
 #[cfg(test)]
 pub mod tests {
-    use super::{generator_input, move_pawn, part1, part2, DeterministicDie};
+    use aoc::parse::mangle;
 
-    static INPUT: &str = "Player 1 starting position: 4\nPlayer 2 starting position: 8";
+    use super::{move_pawn, parse, part1, part2, DeterministicDie, INPUT};
 
     #[test]
     fn generator() {
-        let input = generator_input(INPUT);
+        let input = parse(INPUT).unwrap();
         assert_eq!(input, (4, 8));
     }
 
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
     #[test]
     fn day21_part1() {
-        let input = generator_input(INPUT);
+        let input = parse(INPUT).unwrap();
         assert_eq!(part1(&input), 739785);
     }
 
     #[test]
     fn day21_part2() {
-        let input = generator_input(INPUT);
+        let input = parse(INPUT).unwrap();
         assert_eq!(part2(&input), 444356092776315);
     }
 
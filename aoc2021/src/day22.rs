@@ -1,43 +1,38 @@
 use std::collections::{HashMap, HashSet};
 
-use itertools::Itertools;
+use aoc::{
+    parse::{lines, ParseError},
+    solution::{Answer, Example, Solution},
+};
 
 #[aoc_generator(day22)]
-fn generator_input(input: &str) -> Vec<Step> {
-    input
-        .lines()
-        .map(|l| {
-            let (command_str, coords) = l.split_whitespace().collect_tuple().unwrap();
+pub fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+    lines(22, input)
+        .map(|line| {
+            let (command_str, coords) = line.split_once(line.text, " ")?;
             let command = match command_str {
                 "on" => State::On,
                 "off" => State::Off,
-                _ => unreachable!(),
+                _ => return Err(line.error(command_str, "expected `on` or `off`")),
             };
-            let (x, y, z) = coords
-                .split(',')
-                .map(|c| {
-                    let (left, right) = c
-                        .split('=')
-                        .last()
-                        .unwrap()
-                        .split("..")
-                        .collect_tuple()
-                        .unwrap();
-                    (
-                        left.parse::<i128>().unwrap(),
-                        right.parse::<i128>().unwrap(),
-                    )
-                })
-                .collect_tuple()
-                .unwrap();
-            let cube = Cube { x, y, z };
-            Step { command, cube }
+            let (x_raw, rest) = line.split_once(coords, ",")?;
+            let (y_raw, z_raw) = line.split_once(rest, ",")?;
+            let range = |raw: &str, axis: &str| -> Result<(i128, i128), ParseError> {
+                let (left, right) = line.split_once(line.strip_prefix(raw, axis)?, "..")?;
+                Ok((line.parse(left)?, line.parse(right)?))
+            };
+            let cube = Cube {
+                x: range(x_raw, "x=")?,
+                y: range(y_raw, "y=")?,
+                z: range(z_raw, "z=")?,
+            };
+            Ok(Step { command, cube })
         })
-        .collect_vec()
+        .collect()
 }
 
 #[aoc(day22, part1)]
-fn part1(input: &[Step]) -> usize {
+pub fn part1(input: &[Step]) -> usize {
     let mut reactor = Reactor::new();
     for step in input {
         if step.in_bounds(-50, 50) {
@@ -48,12 +43,12 @@ fn part1(input: &[Step]) -> usize {
 }
 
 #[aoc(day22, part2)]
-fn part2(input: &[Step]) -> i128 {
+pub fn part2(input: &[Step]) -> i128 {
     let mut cubes: HashMap<Cube, i128> = HashMap::new();
 
     for step in input {
         for (old_cube, old_val) in cubes.clone() {
-            if let Some(inter) = intersection(&step.cube, &old_cube) {
+            if let Some(inter) = step.cube.intersection(&old_cube) {
                 *cubes.entry(inter).or_insert(0) -= old_val;
             }
         }
@@ -67,14 +62,32 @@ fn part2(input: &[Step]) -> i128 {
         .fold(0, |acc, (cube, value)| acc + cube.size() * value)
 }
 
-fn intersection(left: &Cube, right: &Cube) -> Option<Cube> {
-    let x = (left.x.0.max(right.x.0), left.x.1.min(right.x.1));
-    let y = (left.y.0.max(right.y.0), left.y.1.min(right.y.1));
-    let z = (left.z.0.max(right.z.0), left.z.1.min(right.z.1));
-    if [x, y, z].iter().all(|(lower, upper)| lower <= upper) {
-        Some(Cube { x, y, z })
-    } else {
-        None
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(INPUT).part1(590784),
+            Example::new(INPUT_2)
+                .part1(474140)
+                .part2(2758514936282235i64),
+        ]
     }
 }
 
@@ -103,10 +116,10 @@ impl Reactor {
     }
 }
 
-#[derive(Debug, PartialEq)]
-struct Step {
-    command: State,
-    cube: Cube,
+#[derive(Debug, PartialEq, Clone)]
+pub struct Step {
+    pub command: State,
+    pub cube: Cube,
 }
 
 impl Step {
@@ -117,30 +130,41 @@ impl Step {
     }
 }
 
-#[derive(Debug, PartialEq)]
-enum State {
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum State {
     On,
     Off,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-struct Cube {
-    x: (i128, i128),
-    y: (i128, i128),
-    z: (i128, i128),
+/// An axis-aligned cuboid of cubes, given as an inclusive range of
+/// coordinates along each axis.
+pub struct Cube {
+    pub x: (i128, i128),
+    pub y: (i128, i128),
+    pub z: (i128, i128),
 }
 
 impl Cube {
-    fn size(&self) -> i128 {
+    /// The number of cubes in the cuboid.
+    pub fn size(&self) -> i128 {
         (self.x.1 - self.x.0 + 1) * (self.y.1 - self.y.0 + 1) * (self.z.1 - self.z.0 + 1)
     }
-}
 
-#[cfg(test)]
-pub mod tests {
-    use super::{generator_input, part1, part2, Cube, State, Step};
+    /// The cubes that are in both cuboids, if there are any.
+    pub fn intersection(&self, other: &Cube) -> Option<Cube> {
+        let x = (self.x.0.max(other.x.0), self.x.1.min(other.x.1));
+        let y = (self.y.0.max(other.y.0), self.y.1.min(other.y.1));
+        let z = (self.z.0.max(other.z.0), self.z.1.min(other.z.1));
+        if [x, y, z].iter().all(|(lower, upper)| lower <= upper) {
+            Some(Cube { x, y, z })
+        } else {
+            None
+        }
+    }
+}
 
-    static INPUT: &str = r#"on x=-20..26,y=-36..17,z=-47..7
+static INPUT: &str = r#"on x=-20..26,y=-36..17,z=-47..7
 on x=-20..33,y=-21..23,z=-26..28
 on x=-22..28,y=-29..23,z=-38..16
 on x=-46..7,y=-6..46,z=-50..-1
@@ -163,7 +187,7 @@ on x=-41..9,y=-7..43,z=-33..15
 on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
 on x=967..23432,y=45373..81175,z=27513..53682"#;
 
-    static INPUT_2: &str = r#"on x=-5..47,y=-31..22,z=-19..33
+static INPUT_2: &str = r#"on x=-5..47,y=-31..22,z=-19..33
 on x=-44..5,y=-27..21,z=-14..35
 on x=-49..-1,y=-11..42,z=-10..38
 on x=-20..34,y=-40..6,z=-44..1
@@ -224,9 +248,19 @@ off x=-70369..-16548,y=22648..78696,z=-1892..86821
 on x=-53470..21291,y=-120233..-33476,z=-44150..38147
 off x=-93533..-4276,y=-16170..68771,z=-104985..-24507"#;
 
+#[cfg(test)]
+pub mod tests {
+    use aoc::{
+        diff::{check, shrink_vec},
+        parse::mangle,
+        rng::Rng,
+    };
+
+    use super::{parse, part1, part2, Cube, State, Step, INPUT, INPUT_2};
+
     #[test]
     fn generator() {
-        let input = generator_input(INPUT);
+        let input = parse(INPUT).unwrap();
         assert_eq!(input.len(), 22);
         assert_eq!(
             input[0],
@@ -263,15 +297,94 @@ off x=-93533..-4276,y=-16170..68771,z=-104985..-24507"#;
         );
     }
 
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day22_intersection() {
+        let cube = |x, y, z| Cube { x, y, z };
+        let a = cube((0, 9), (0, 9), (0, 9));
+        let b = cube((5, 14), (-5, 4), (9, 9));
+        assert_eq!(a.intersection(&b), Some(cube((5, 9), (0, 4), (9, 9))));
+        assert_eq!(a.intersection(&b).unwrap().size(), 25);
+        assert_eq!(a.intersection(&cube((10, 12), (0, 9), (0, 9))), None);
+    }
+
     #[test]
     fn day22_part1() {
-        let input = generator_input(INPUT);
+        let input = parse(INPUT).unwrap();
         assert_eq!(part1(&input), 590784);
     }
 
     #[test]
     fn day22_part2() {
-        let input = generator_input(INPUT_2);
+        let input = parse(INPUT_2).unwrap();
         assert_eq!(part2(&input), 2758514936282235);
     }
+
+    fn small_steps(rng: &mut Rng) -> Vec<Step> {
+        let range = |rng: &mut Rng| {
+            let start = rng.range(-5..=5);
+            (start as i128, (start + rng.range(0..=4)).min(5) as i128)
+        };
+        (0..rng.range(1..=8))
+            .map(|_| Step {
+                command: match rng.chance(0.6) {
+                    true => State::On,
+                    false => State::Off,
+                },
+                cube: Cube {
+                    x: range(rng),
+                    y: range(rng),
+                    z: range(rng),
+                },
+            })
+            .collect()
+    }
+
+    /// Steps with one side of the cube moved inwards by one, or switched on.
+    fn shrink_step(step: &Step) -> Vec<Step> {
+        let mut smaller = Vec::new();
+        if step.command == State::Off {
+            smaller.push(Step {
+                command: State::On,
+                cube: step.cube,
+            });
+        }
+        for axis in 0..3 {
+            for side in 0..2 {
+                let mut cube = step.cube;
+                let range = match axis {
+                    0 => &mut cube.x,
+                    1 => &mut cube.y,
+                    _ => &mut cube.z,
+                };
+                if range.0 < range.1 {
+                    match side {
+                        0 => range.0 += 1,
+                        _ => range.1 -= 1,
+                    }
+                    smaller.push(Step {
+                        command: step.command,
+                        cube,
+                    });
+                }
+            }
+        }
+        smaller
+    }
+
+    #[test]
+    fn day22_differential() {
+        let result = check(
+            200,
+            small_steps,
+            |steps| shrink_vec(steps, shrink_step),
+            |steps| part1(steps) as i128,
+            |steps| part2(steps),
+        );
+        assert_eq!(result, Ok(()));
+    }
 }
@@ -0,0 +1,606 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap},
+    fmt,
+};
+
+use aoc::{
+    parse::{lines, Line, ParseError},
+    solution::{Answer, Example, Solution},
+};
+
+/// The most kinds of amphipods, and so rooms, in a burrow. Kind `i` takes
+/// `10^i` energy per step, so the dearest step costs `10^9`; that keeps the
+/// total energy of a plan far from the limit of a 64-bit `usize` for any
+/// burrow small enough to search.
+const MAX_ROOMS: usize = 10;
+
+#[aoc_generator(day23)]
+pub fn parse<'a>(input: &'a str) -> Result<Burrow, ParseError> {
+    let lines = lines(23, input).collect::<Vec<_>>();
+    let is_wall =
+        |line: &Line| !line.text.is_empty() && line.text.trim_start().chars().all(|c| c == '#');
+    let [top, hallway, levels @ .., bottom] = lines.as_slice() else {
+        return Err(ParseError::end_of_input(23, input, "a burrow with rooms"));
+    };
+    for wall in [top, bottom] {
+        if !is_wall(wall) {
+            return Err(wall.error(wall.text, "expected a wall"));
+        }
+    }
+    if levels.is_empty() {
+        return Err(bottom.error(bottom.text, "expected a level of rooms"));
+    }
+
+    // Rooms are the open cells of the first level.
+    let doors = open(&levels[0])
+        .iter()
+        .map(|&(column, _)| column.wrapping_sub(1))
+        .collect::<Vec<_>>();
+    let amphipods = doors.len();
+    let width = hallway.text.chars().count();
+    if !hallway.text.starts_with('#')
+        || !hallway.text.ends_with('#')
+        || width < 3
+        || doors.iter().any(|&door| door >= width - 2)
+    {
+        return Err(hallway.error(hallway.text, "expected a hallway above every room"));
+    }
+    if !(1..=MAX_ROOMS).contains(&amphipods) {
+        return Err(levels[0].error(
+            levels[0].text,
+            format!("expected between 1 and {} rooms", MAX_ROOMS),
+        ));
+    }
+    // Every amphipod with where it was found, to count them by kind.
+    let mut found = vec![];
+    let mut read = |line: &Line<'a>, token: &'a str| {
+        let cell = cell(line, token, amphipods)?;
+        if let Some(amphipod) = cell {
+            found.push((*line, token, amphipod));
+        }
+        Ok(cell)
+    };
+    let hallway = open(hallway)
+        .into_iter()
+        .map(|(_, token)| read(hallway, token))
+        .collect::<Result<Vec<_>, _>>()?;
+    if hallway.len() != width - 2 {
+        return Err(lines[1].error(lines[1].text, "expected only `.` and amphipods"));
+    }
+
+    let mut rooms = vec![vec![]; amphipods];
+    for level in levels {
+        let cells = open(level);
+        if cells
+            .iter()
+            .map(|&(column, _)| column.wrapping_sub(1))
+            .ne(doors.iter().copied())
+        {
+            return Err(level.error(level.text, "expected the rooms of the first level"));
+        }
+        for (room, (_, token)) in cells.into_iter().enumerate() {
+            rooms[room].push(read(level, token)?);
+        }
+    }
+
+    // There are as many amphipods of each kind as a room holds.
+    let depth = levels.len();
+    let mut counts = vec![0; amphipods];
+    for (line, token, amphipod) in found {
+        counts[amphipod as usize] += 1;
+        if counts[amphipod as usize] > depth {
+            return Err(line.error(
+                token,
+                format!("expected only {} `{}`", depth, letter(amphipod)),
+            ));
+        }
+    }
+    if let Some(amphipod) = counts.iter().position(|&count| count < depth) {
+        return Err(levels[0].error(
+            levels[0].text,
+            format!(
+                "expected {} `{}`, found {}",
+                depth,
+                letter(amphipod as u8),
+                counts[amphipod]
+            ),
+        ));
+    }
+
+    Ok(Burrow {
+        doors,
+        hallway,
+        rooms,
+    })
+}
+
+#[aoc(day23, part1)]
+pub fn part1(input: &Burrow) -> usize {
+    organise(input)
+        .expect("the amphipods cannot be organised")
+        .energy
+}
+
+/// Only burrows like the puzzle's, with four rooms of two levels, can be
+/// unfolded; others have no answer.
+#[aoc(day23, part2)]
+pub fn part2(input: &Burrow) -> Answer {
+    match input.unfold() {
+        Some(burrow) => organise(&burrow)
+            .expect("the amphipods cannot be organised")
+            .energy
+            .into(),
+        None => Answer::None,
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+
+    type Input = Burrow;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input))
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(12521).part2(44169)]
+    }
+}
+
+fn cell(line: &Line, token: &str, amphipods: usize) -> Result<Option<u8>, ParseError> {
+    match token.as_bytes() {
+        b"." => Ok(None),
+        &[c] if c.is_ascii_uppercase() && ((c - b'A') as usize) < amphipods => Ok(Some(c - b'A')),
+        _ => Err(line.error(
+            token,
+            format!(
+                "expected `.` or an amphipod up to `{}`",
+                letter(amphipods as u8 - 1)
+            ),
+        )),
+    }
+}
+
+/// The cells of a line that are not walls, with their column. Each is either
+/// empty or holds an amphipod.
+fn open<'a>(line: &Line<'a>) -> Vec<(usize, &'a str)> {
+    line.chars()
+        .enumerate()
+        .filter(|(_, (c, _))| *c != '#' && *c != ' ')
+        .map(|(column, (_, token))| (column, token))
+        .collect()
+}
+
+fn letter(amphipod: u8) -> char {
+    (b'A' + amphipod) as char
+}
+
+/// The amphipods in the hallway and in the rooms below it. Amphipod `i` is
+/// written as the `i`th letter, belongs into room `i` and takes `10^i` energy
+/// per step.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+pub struct Burrow {
+    /// The hallway cell right above each room.
+    doors: Vec<usize>,
+    hallway: Vec<Option<u8>>,
+    /// Each room from top to bottom.
+    rooms: Vec<Vec<Option<u8>>>,
+}
+
+/// A cell of the burrow: a hallway cell, or a room and how far down it is.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Place {
+    Hallway(usize),
+    Room(usize, usize),
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Move {
+    pub amphipod: u8,
+    pub from: Place,
+    pub to: Place,
+    pub energy: usize,
+}
+
+/// The cheapest way to organise a burrow.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Plan {
+    pub energy: usize,
+    pub moves: Vec<Move>,
+}
+
+impl Burrow {
+    /// Inserts the two folded levels of the second part below the top one,
+    /// if the burrow has the four rooms of two levels they are made for.
+    pub fn unfold(&self) -> Option<Burrow> {
+        if self.rooms.len() != 4 || self.rooms[0].len() != 2 {
+            return None;
+        }
+        let mut burrow = self.clone();
+        for (room, inserted) in burrow
+            .rooms
+            .iter_mut()
+            .zip([[3, 3], [2, 1], [1, 0], [0, 2]])
+        {
+            room.splice(1..1, inserted.map(Some));
+        }
+        Some(burrow)
+    }
+
+    pub fn is_organised(&self) -> bool {
+        self.hallway.iter().all(Option::is_none)
+            && (0..self.rooms.len()).all(|room| self.settled(room, 0))
+    }
+
+    /// The burrow after `step`, which has to be one of its moves.
+    pub fn apply(&self, step: &Move) -> Burrow {
+        let mut burrow = self.clone();
+        *burrow.cell_mut(step.from) = None;
+        *burrow.cell_mut(step.to) = Some(step.amphipod);
+        burrow
+    }
+
+    fn cell_mut(&mut self, place: Place) -> &mut Option<u8> {
+        match place {
+            Place::Hallway(cell) => &mut self.hallway[cell],
+            Place::Room(room, depth) => &mut self.rooms[room][depth],
+        }
+    }
+
+    fn energy(amphipod: u8) -> usize {
+        10usize.pow(amphipod as u32)
+    }
+
+    /// Whether everything from `depth` down in `room` belongs there.
+    fn settled(&self, room: usize, depth: usize) -> bool {
+        self.rooms[room][depth..]
+            .iter()
+            .all(|&cell| cell == Some(room as u8))
+    }
+
+    /// The deepest free cell of the amphipod's room, if only amphipods of its
+    /// own kind are in there.
+    fn free_depth(&self, amphipod: u8) -> Option<usize> {
+        let room = &self.rooms[amphipod as usize];
+        room.iter()
+            .all(|&cell| cell.is_none() || cell == Some(amphipod))
+            .then(|| room.iter().rposition(Option::is_none))
+            .flatten()
+    }
+
+    /// Whether the hallway is empty on the way from `from` to `to`, not
+    /// counting `from` itself.
+    fn clear(&self, from: usize, to: usize) -> bool {
+        let path = match from.cmp(&to) {
+            Ordering::Less => from + 1..=to,
+            Ordering::Greater => to..=from - 1,
+            Ordering::Equal => return true,
+        };
+        self.hallway[path].iter().all(Option::is_none)
+    }
+
+    /// Every move allowed by the rules: out of a room onto a hallway cell
+    /// that is not in front of a door, or into the amphipod's own room once
+    /// only its own kind is in there.
+    pub fn moves(&self) -> Vec<Move> {
+        let mut moves = vec![];
+
+        for (cell, &amphipod) in self.hallway.iter().enumerate() {
+            let Some(amphipod) = amphipod else { continue };
+            let door = self.doors[amphipod as usize];
+            if let Some(depth) = self.free_depth(amphipod) {
+                if self.clear(cell, door) {
+                    moves.push(Move {
+                        amphipod,
+                        from: Place::Hallway(cell),
+                        to: Place::Room(amphipod as usize, depth),
+                        energy: (cell.abs_diff(door) + depth + 1) * Burrow::energy(amphipod),
+                    });
+                }
+            }
+        }
+
+        for (room, &door) in self.doors.iter().enumerate() {
+            let Some(depth) = self.rooms[room].iter().position(Option::is_some) else {
+                continue;
+            };
+            if self.settled(room, depth) || self.hallway[door].is_some() {
+                continue;
+            }
+            let amphipod = self.rooms[room][depth].unwrap();
+            let from = Place::Room(room, depth);
+            let target = self.doors[amphipod as usize];
+            if let Some(target_depth) = self.free_depth(amphipod) {
+                if room != amphipod as usize && self.clear(door, target) {
+                    let steps = depth + 1 + door.abs_diff(target) + target_depth + 1;
+                    moves.push(Move {
+                        amphipod,
+                        from,
+                        to: Place::Room(amphipod as usize, target_depth),
+                        energy: steps * Burrow::energy(amphipod),
+                    });
+                }
+            }
+            for cell in 0..self.hallway.len() {
+                if !self.doors.contains(&cell) && self.clear(door, cell) {
+                    moves.push(Move {
+                        amphipod,
+                        from,
+                        to: Place::Hallway(cell),
+                        energy: (depth + 1 + door.abs_diff(cell)) * Burrow::energy(amphipod),
+                    });
+                }
+            }
+        }
+
+        moves
+    }
+
+    /// The energy it would take if amphipods could walk through each other,
+    /// which never overestimates.
+    fn lower_bound(&self) -> usize {
+        let hallway = self
+            .hallway
+            .iter()
+            .enumerate()
+            .filter_map(|(cell, &amphipod)| {
+                let amphipod = amphipod?;
+                let steps = cell.abs_diff(self.doors[amphipod as usize]) + 1;
+                Some(steps * Burrow::energy(amphipod))
+            });
+        let rooms = self.rooms.iter().enumerate().flat_map(|(room, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .filter_map(move |(depth, &amphipod)| {
+                    let amphipod = amphipod?;
+                    let steps = match amphipod as usize == room {
+                        true if self.settled(room, depth) => return None,
+                        // Out of the way of the ones below, and back in.
+                        true => depth + 1 + 2 + 1,
+                        false => {
+                            depth + 1 + self.doors[room].abs_diff(self.doors[amphipod as usize]) + 1
+                        }
+                    };
+                    Some(steps * Burrow::energy(amphipod))
+                })
+        });
+        hallway.chain(rooms).sum()
+    }
+}
+
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let show = |cell: &Option<u8>| cell.map_or('.', letter);
+        let width = self.hallway.len() + 2;
+        let (first, last) = (self.doors[0], self.doors[self.doors.len() - 1]);
+
+        writeln!(f, "{}", "#".repeat(width))?;
+        writeln!(f, "#{}#", self.hallway.iter().map(show).collect::<String>())?;
+        for depth in 0..self.rooms[0].len() {
+            let level = (0..width)
+                .map(
+                    |column| match self.doors.iter().position(|&door| door + 1 == column) {
+                        Some(room) => show(&self.rooms[room][depth]),
+                        None if depth == 0 || (first..=last + 2).contains(&column) => '#',
+                        None => ' ',
+                    },
+                )
+                .collect::<String>();
+            writeln!(f, "{}", level.trim_end())?;
+        }
+        write!(f, "{}{}", " ".repeat(first), "#".repeat(last + 3 - first))
+    }
+}
+
+impl fmt::Display for Place {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Place::Hallway(cell) => write!(f, "hallway cell {}", cell),
+            Place::Room(room, depth) => {
+                write!(f, "room {} level {}", letter(*room as u8), depth + 1)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} from {} to {} ({} energy)",
+            letter(self.amphipod),
+            self.from,
+            self.to,
+            self.energy
+        )
+    }
+}
+
+/// Finds the cheapest way to organise the burrow with a best-first search,
+/// guided by `Burrow::lower_bound`. `None` if there is no way at all.
+pub fn organise(burrow: &Burrow) -> Option<Plan> {
+    let mut best = HashMap::from([(burrow.clone(), 0)]);
+    let mut came_from: HashMap<Burrow, (Burrow, Move)> = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse((burrow.lower_bound(), 0, burrow.clone()))]);
+
+    while let Some(Reverse((_, energy, state))) = queue.pop() {
+        if state.is_organised() {
+            let mut moves = vec![];
+            let mut current = state;
+            while let Some((previous, step)) = came_from.remove(&current) {
+                moves.push(step);
+                current = previous;
+            }
+            moves.reverse();
+            return Some(Plan { energy, moves });
+        }
+        if energy > best[&state] {
+            continue;
+        }
+        for step in state.moves() {
+            let next = state.apply(&step);
+            let next_energy = energy + step.energy;
+            if best.get(&next).is_none_or(|&known| next_energy < known) {
+                best.insert(next.clone(), next_energy);
+                came_from.insert(next.clone(), (state.clone(), step));
+                queue.push(Reverse((
+                    next_energy + next.lower_bound(),
+                    next_energy,
+                    next,
+                )));
+            }
+        }
+    }
+    None
+}
+
+static INPUT: &str = r#"#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########"#;
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::{parse::mangle, solution::Answer};
+
+    use super::{organise, parse, part1, part2, Move, Place, INPUT};
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(input.doors, vec![2, 4, 6, 8]);
+        assert_eq!(input.hallway, vec![None; 11]);
+        assert_eq!(
+            input.rooms,
+            vec![
+                vec![Some(1), Some(0)],
+                vec![Some(2), Some(3)],
+                vec![Some(1), Some(2)],
+                vec![Some(3), Some(0)],
+            ]
+        );
+        assert_eq!(input.to_string(), INPUT);
+        assert_eq!(
+            parse(&INPUT.replace("#D#C", "#E#C"))
+                .unwrap_err()
+                .to_string(),
+            "day23 line 4, column 6: expected `.` or an amphipod up to `D` (found `E`)"
+        );
+        assert_eq!(
+            parse(&INPUT.replace("  #A#D#C#A#", "  #A#D#C#A#.#"))
+                .unwrap_err()
+                .to_string(),
+            "day23 line 4, column 1: expected the rooms of the first level (found `  #A#D#C#A#.#`)"
+        );
+        assert_eq!(
+            parse("#######\n#.....#\n##A#A##\n #####")
+                .unwrap_err()
+                .to_string(),
+            "day23 line 3, column 5: expected only 1 `A` (found `A`)"
+        );
+        assert_eq!(
+            parse("#############\n#...........#\n###B#C#B#D###\n  #########")
+                .unwrap_err()
+                .to_string(),
+            "day23 line 3, column 8: expected only 1 `B` (found `B`)"
+        );
+        assert_eq!(
+            parse("#############\n#...........#\n###.#B#C#D###\n  #########")
+                .unwrap_err()
+                .to_string(),
+            "day23 line 3, column 1: expected 1 `A`, found 0 (found `###.#B#C#D###`)"
+        );
+        assert_eq!(
+            parse("#######\n#A....#\n##.#B##\n #####").map(|burrow| burrow.rooms),
+            Ok(vec![vec![None], vec![Some(1)]])
+        );
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day23_unfold() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(
+            input.unfold().unwrap().to_string(),
+            "\
+#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########"
+        );
+        let two_rooms = parse("#######\n#.....#\n##B#A##\n #####").unwrap();
+        assert_eq!(two_rooms.unfold(), None);
+        assert_eq!(input.unfold().unwrap().unfold(), None);
+    }
+
+    #[test]
+    fn day23_moves() {
+        // A shorter hallway with two rooms of a single level: `B` has to wait
+        // for `A` to make way.
+        let input = parse("#######\n#.....#\n##B#A##\n #####").unwrap();
+        let plan = organise(&input).unwrap();
+        assert_eq!(plan.energy, 46);
+        assert_eq!(plan.moves.iter().map(|step| step.energy).sum::<usize>(), 46);
+
+        let mut burrow = input;
+        for step in &plan.moves {
+            assert!(burrow.moves().contains(step));
+            burrow = burrow.apply(step);
+        }
+        assert!(burrow.is_organised());
+        assert_eq!(burrow.to_string(), "#######\n#.....#\n##A#B##\n #####");
+
+        assert_eq!(
+            Move {
+                amphipod: 1,
+                from: Place::Room(0, 0),
+                to: Place::Hallway(2),
+                energy: 20,
+            }
+            .to_string(),
+            "B from room A level 1 to hallway cell 2 (20 energy)"
+        );
+    }
+
+    #[test]
+    fn day23_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 12521);
+        let plan = organise(&input).unwrap();
+        let end = plan
+            .moves
+            .iter()
+            .fold(input, |burrow, step| burrow.apply(step));
+        assert!(end.is_organised());
+    }
+
+    #[test]
+    fn day23_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), Answer::Int(44169));
+
+        let two_rooms = parse("#######\n#.....#\n##B#A##\n #####").unwrap();
+        assert_eq!(part2(&two_rooms), Answer::None);
+    }
+}
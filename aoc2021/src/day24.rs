@@ -0,0 +1,613 @@
+use std::fmt;
+
+use aoc::{
+    parse::{lines, Line, ParseError},
+    solution::{Answer, Solution},
+};
+use itertools::Itertools;
+
+#[aoc_generator(day24)]
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    lines(24, input)
+        .map(|line| {
+            let mut tokens = line.text.split(' ');
+            let op = tokens.next().unwrap();
+            let mut next = |what: &str| {
+                tokens.next().ok_or_else(|| {
+                    line.error(&line.text[line.text.len()..], format!("expected {}", what))
+                })
+            };
+            let instruction = match op {
+                "inp" => Instruction::Inp(register(&line, next("a register")?)?),
+                "add" | "mul" | "div" | "mod" | "eql" => {
+                    let a = register(&line, next("a register")?)?;
+                    let b = operand(&line, next("a register or a number")?)?;
+                    match op {
+                        "add" => Instruction::Add(a, b),
+                        "mul" => Instruction::Mul(a, b),
+                        "div" => Instruction::Div(a, b),
+                        "mod" => Instruction::Mod(a, b),
+                        _ => Instruction::Eql(a, b),
+                    }
+                }
+                _ => {
+                    return Err(
+                        line.error(op, "expected `inp`, `add`, `mul`, `div`, `mod` or `eql`")
+                    )
+                }
+            };
+            match tokens.next() {
+                Some(extra) => Err(line.error(extra, "expected end of line")),
+                None => Ok(instruction),
+            }
+        })
+        .collect()
+}
+
+#[aoc(day24, part1)]
+pub fn part1(input: &[Instruction]) -> Result<u64, String> {
+    model_numbers(input).map(|(largest, _)| largest)
+}
+
+#[aoc(day24, part2)]
+pub fn part2(input: &[Instruction]) -> Result<u64, String> {
+    model_numbers(input).map(|(_, smallest)| smallest)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        part2(input).map(Answer::from)
+    }
+}
+
+fn register(line: &Line, token: &str) -> Result<Register, ParseError> {
+    match token {
+        "w" => Ok(Register::W),
+        "x" => Ok(Register::X),
+        "y" => Ok(Register::Y),
+        "z" => Ok(Register::Z),
+        _ => Err(line.error(token, "expected `w`, `x`, `y` or `z`")),
+    }
+}
+
+fn operand(line: &Line, token: &str) -> Result<Operand, ParseError> {
+    match register(line, token) {
+        Ok(register) => Ok(Operand::Register(register)),
+        Err(_) => token
+            .parse()
+            .map(Operand::Value)
+            .map_err(|_| line.error(token, "expected a register or a number")),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operand {
+    Register(Register),
+    Value(i64),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Register::W => 'w',
+            Register::X => 'x',
+            Register::Y => 'y',
+            Register::Z => 'z',
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Register(register) => write!(f, "{}", register),
+            Operand::Value(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (op, a, b) = match self {
+            Instruction::Inp(a) => return write!(f, "inp {}", a),
+            Instruction::Add(a, b) => ("add", a, b),
+            Instruction::Mul(a, b) => ("mul", a, b),
+            Instruction::Div(a, b) => ("div", a, b),
+            Instruction::Mod(a, b) => ("mod", a, b),
+            Instruction::Eql(a, b) => ("eql", a, b),
+        };
+        write!(f, "{} {} {}", op, a, b)
+    }
+}
+
+/// Why the ALU stopped; `instruction` counts from 1, like the lines of the
+/// program.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AluError {
+    DivisionByZero { instruction: usize },
+    InvalidModulo { instruction: usize },
+    MissingInput { instruction: usize },
+    Overflow { instruction: usize },
+}
+
+impl fmt::Display for AluError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AluError::DivisionByZero { instruction } => {
+                write!(f, "instruction {}: division by zero", instruction)
+            }
+            AluError::InvalidModulo { instruction } => write!(
+                f,
+                "instruction {}: modulo of a negative number or by a non-positive one",
+                instruction
+            ),
+            AluError::MissingInput { instruction } => {
+                write!(f, "instruction {}: no input left", instruction)
+            }
+            AluError::Overflow { instruction } => {
+                write!(f, "instruction {}: overflow", instruction)
+            }
+        }
+    }
+}
+
+/// The arithmetic logic unit of the submarine, with its four registers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Alu {
+    pub registers: [i64; 4],
+}
+
+impl Alu {
+    pub fn get(&self, register: Register) -> i64 {
+        self.registers[register as usize]
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.get(register),
+            Operand::Value(value) => value,
+        }
+    }
+
+    /// Runs `program` on a fresh ALU, reading `inp` values from `input`.
+    /// Division truncates towards zero.
+    pub fn run(
+        program: &[Instruction],
+        input: impl IntoIterator<Item = i64>,
+    ) -> Result<Alu, AluError> {
+        let mut alu = Alu::default();
+        let mut input = input.into_iter();
+        for (i, &instruction) in program.iter().enumerate() {
+            let line = i + 1;
+            let (a, result) = match instruction {
+                Instruction::Inp(a) => (
+                    a,
+                    input
+                        .next()
+                        .ok_or(AluError::MissingInput { instruction: line })?,
+                ),
+                Instruction::Add(a, b) => (
+                    a,
+                    alu.get(a)
+                        .checked_add(alu.value(b))
+                        .ok_or(AluError::Overflow { instruction: line })?,
+                ),
+                Instruction::Mul(a, b) => (
+                    a,
+                    alu.get(a)
+                        .checked_mul(alu.value(b))
+                        .ok_or(AluError::Overflow { instruction: line })?,
+                ),
+                Instruction::Div(a, b) => match alu.value(b) {
+                    0 => return Err(AluError::DivisionByZero { instruction: line }),
+                    b => (
+                        a,
+                        alu.get(a)
+                            .checked_div(b)
+                            .ok_or(AluError::Overflow { instruction: line })?,
+                    ),
+                },
+                Instruction::Mod(a, b) => match (alu.get(a), alu.value(b)) {
+                    (a_value, b) if a_value < 0 || b <= 0 => {
+                        return Err(AluError::InvalidModulo { instruction: line })
+                    }
+                    (a_value, b) => (a, a_value % b),
+                },
+                Instruction::Eql(a, b) => (a, (alu.get(a) == alu.value(b)) as i64),
+            };
+            alu.registers[a as usize] = result;
+        }
+        Ok(alu)
+    }
+}
+
+/// The parameters that tell the digit blocks of MONAD apart. Every block
+/// reads a digit `w`, compares it with the last digit pushed onto `z` plus
+/// `check`, pops that digit if `pop` is set, and pushes `w + offset` unless
+/// the comparison matched. `z` is a stack of base 26 digits.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Block {
+    pub pop: bool,
+    pub check: i64,
+    pub offset: i64,
+}
+
+impl Block {
+    pub fn instructions(&self) -> Vec<Instruction> {
+        use Instruction::*;
+        use Operand::{Register as R, Value as V};
+        use Register::*;
+        vec![
+            Inp(W),
+            Mul(X, V(0)),
+            Add(X, R(Z)),
+            Mod(X, V(26)),
+            Div(Z, V(if self.pop { 26 } else { 1 })),
+            Add(X, V(self.check)),
+            Eql(X, R(W)),
+            Eql(X, V(0)),
+            Mul(Y, V(0)),
+            Add(Y, V(25)),
+            Mul(Y, R(X)),
+            Add(Y, V(1)),
+            Mul(Z, R(Y)),
+            Mul(Y, V(0)),
+            Add(Y, R(W)),
+            Add(Y, V(self.offset)),
+            Mul(Y, R(X)),
+            Add(Z, R(Y)),
+        ]
+    }
+
+    /// Recognises a block by its parameters, which have to be numbers at the
+    /// right places, and by comparing everything else with the template.
+    fn parse(instructions: &[Instruction]) -> Option<Block> {
+        let (
+            Instruction::Div(_, Operand::Value(div)),
+            Instruction::Add(_, Operand::Value(check)),
+            Instruction::Add(_, Operand::Value(offset)),
+        ) = (
+            instructions.get(4)?,
+            instructions.get(5)?,
+            instructions.get(15)?,
+        )
+        else {
+            return None;
+        };
+        let block = Block {
+            pop: *div == 26,
+            check: *check,
+            offset: *offset,
+        };
+        (block.instructions() == instructions).then_some(block)
+    }
+}
+
+/// The number of digits in a model number, one block of MONAD each.
+pub const DIGITS: usize = 14;
+
+/// The largest and the smallest model number MONAD accepts.
+pub fn model_numbers(program: &[Instruction]) -> Result<(u64, u64), String> {
+    let blocks = program
+        .chunks(18)
+        .enumerate()
+        .map(|(i, chunk)| {
+            Block::parse(chunk).ok_or_else(|| format!("block {} does not look like MONAD", i + 1))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if blocks.len() != DIGITS {
+        return Err(format!(
+            "expected {} blocks, one per digit, found {}",
+            DIGITS,
+            blocks.len()
+        ));
+    }
+
+    let (largest, smallest) = best_digits(&blocks)?;
+    let number = |digits: &[i64]| digits.iter().fold(0, |n, &d| n * 10 + d as u64);
+    for digits in [&largest, &smallest] {
+        let z = Alu::run(program, digits.iter().copied())
+            .map_err(|err| err.to_string())?
+            .get(Register::Z);
+        if z != 0 {
+            return Err(format!("MONAD rejects {} with z = {}", number(digits), z));
+        }
+    }
+    Ok((number(&largest), number(&smallest)))
+}
+
+/// The digits of the largest and the smallest number `blocks` accept.
+///
+/// Blocks that push only ever compare against `check > 9`, which no digit
+/// can match, so each of them pairs up with a later popping block like
+/// brackets. A pair accepts its digits if `later = earlier + offset + check`,
+/// which pins down the best digits of each pair on its own.
+fn best_digits(blocks: &[Block]) -> Result<(Vec<i64>, Vec<i64>), String> {
+    let mut largest = vec![0; blocks.len()];
+    let mut smallest = vec![0; blocks.len()];
+    let mut stack = vec![];
+    for (i, block) in blocks.iter().enumerate() {
+        if !block.pop {
+            if block.check <= 9 {
+                return Err(format!(
+                    "block {} can push or not, depending on the digit",
+                    i + 1
+                ));
+            }
+            stack.push((i, block.offset));
+            continue;
+        }
+        let (earlier, offset) = stack
+            .pop()
+            .ok_or_else(|| format!("block {} pops from an empty stack", i + 1))?;
+        let difference = offset + block.check;
+        if difference.abs() > 8 {
+            return Err(format!(
+                "no digits make blocks {} and {} match",
+                earlier + 1,
+                i + 1
+            ));
+        }
+        largest[earlier] = 9.min(9 - difference);
+        smallest[earlier] = 1.max(1 - difference);
+        largest[i] = largest[earlier] + difference;
+        smallest[i] = smallest[earlier] + difference;
+    }
+    if let Some((earlier, _)) = stack.pop() {
+        return Err(format!("block {} is never popped", earlier + 1));
+    }
+    Ok((largest, smallest))
+}
+
+/// A MONAD program made of `blocks`, as text.
+pub fn monad(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .flat_map(Block::instructions)
+        .map(|instruction| instruction.to_string())
+        .join("\n")
+}
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::parse::mangle;
+    use itertools::Itertools;
+
+    use super::{
+        best_digits, model_numbers, monad, parse, part1, part2, Alu, AluError, Block, Instruction,
+        Operand, Register,
+    };
+    use crate::gen;
+
+    static INPUT: &str = r#"inp w
+add z w
+mod z 2
+div w 2
+add y w
+mod y 2
+div w 2
+add x w
+mod x 2
+div w 2
+mod w 2"#;
+
+    fn push(check: i64, offset: i64) -> Block {
+        Block {
+            pop: false,
+            check,
+            offset,
+        }
+    }
+
+    fn pop(check: i64, offset: i64) -> Block {
+        Block {
+            pop: true,
+            check,
+            offset,
+        }
+    }
+
+    fn blocks() -> Vec<Block> {
+        vec![
+            push(12, 4),
+            push(11, 11),
+            push(13, 5),
+            push(11, 11),
+            push(14, 14),
+            pop(-10, 7),
+            push(11, 11),
+            pop(-9, 4),
+            pop(-3, 6),
+            push(13, 5),
+            pop(-5, 9),
+            pop(-10, 12),
+            pop(-4, 14),
+            pop(-5, 14),
+        ]
+    }
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(input.len(), 11);
+        assert_eq!(input[0], Instruction::Inp(Register::W));
+        assert_eq!(input[2], Instruction::Mod(Register::Z, Operand::Value(2)));
+        assert_eq!(input.iter().join("\n"), INPUT);
+        assert_eq!(
+            parse("add x -3\nsub x y").unwrap_err().to_string(),
+            "day24 line 2, column 1: expected `inp`, `add`, `mul`, `div`, `mod` or `eql` (found `sub`)"
+        );
+        assert_eq!(
+            parse("mul a 2").unwrap_err().to_string(),
+            "day24 line 1, column 5: expected `w`, `x`, `y` or `z` (found `a`)"
+        );
+        assert_eq!(
+            parse("eql x").unwrap_err().to_string(),
+            "day24 line 1, column 6: expected a register or a number"
+        );
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day24_alu() {
+        let negate = parse("inp x\nmul x -1").unwrap();
+        assert_eq!(Alu::run(&negate, [7]).unwrap().get(Register::X), -7);
+
+        let three_times = parse("inp z\ninp x\nmul z 3\neql z x").unwrap();
+        assert_eq!(Alu::run(&three_times, [2, 6]).unwrap().get(Register::Z), 1);
+        assert_eq!(Alu::run(&three_times, [2, 7]).unwrap().get(Register::Z), 0);
+
+        let binary = parse(INPUT).unwrap();
+        assert_eq!(Alu::run(&binary, [13]).unwrap().registers, [1, 1, 0, 1]);
+
+        let truncate = parse("inp x\ndiv x 2").unwrap();
+        assert_eq!(Alu::run(&truncate, [-7]).unwrap().get(Register::X), -3);
+    }
+
+    #[test]
+    fn day24_alu_errors() {
+        let divide = parse("inp x\ninp y\ndiv x y").unwrap();
+        assert_eq!(
+            Alu::run(&divide, [1, 0]),
+            Err(AluError::DivisionByZero { instruction: 3 })
+        );
+        assert_eq!(
+            Alu::run(&divide, [1]),
+            Err(AluError::MissingInput { instruction: 2 })
+        );
+
+        let modulo = parse("inp x\ninp y\nmod x y").unwrap();
+        for input in [[-1, 5], [5, 0], [5, -2]] {
+            assert_eq!(
+                Alu::run(&modulo, input),
+                Err(AluError::InvalidModulo { instruction: 3 })
+            );
+        }
+        assert_eq!(Alu::run(&modulo, [7, 5]).unwrap().get(Register::X), 2);
+
+        let square = parse("inp x\nmul x x\nmul x x").unwrap();
+        assert_eq!(
+            Alu::run(&square, [1 << 20]),
+            Err(AluError::Overflow { instruction: 3 })
+        );
+        let add = parse("inp x\nadd x 9223372036854775807").unwrap();
+        assert_eq!(
+            Alu::run(&add, [1]),
+            Err(AluError::Overflow { instruction: 2 })
+        );
+        let divide = parse("inp x\ndiv x -1").unwrap();
+        assert_eq!(
+            Alu::run(&divide, [i64::MIN]),
+            Err(AluError::Overflow { instruction: 2 })
+        );
+    }
+
+    #[test]
+    fn day24_blocks_by_brute_force() {
+        let blocks = [push(12, 3), push(10, 8), pop(-6, 2), pop(-4, 7)];
+        let program = parse(&monad(&blocks)).unwrap();
+        let accepted = (0..4)
+            .map(|_| 1..=9)
+            .multi_cartesian_product()
+            .filter(|digits| {
+                Alu::run(&program, digits.iter().copied())
+                    .unwrap()
+                    .get(Register::Z)
+                    == 0
+            })
+            .collect_vec();
+        assert_eq!(
+            best_digits(&blocks),
+            Ok((
+                accepted.iter().max().unwrap().clone(),
+                accepted.iter().min().unwrap().clone()
+            ))
+        );
+    }
+
+    #[test]
+    fn day24_not_monad() {
+        let mut program = parse(&monad(&blocks())).unwrap();
+        program[18 * 3 + 7] = Instruction::Eql(Register::X, Operand::Value(1));
+        assert_eq!(
+            model_numbers(&program),
+            Err("block 4 does not look like MONAD".to_string())
+        );
+
+        let mut unbalanced = blocks();
+        unbalanced[13] = push(12, 3);
+        assert_eq!(
+            model_numbers(&parse(&monad(&unbalanced)).unwrap()),
+            Err("block 14 is never popped".to_string())
+        );
+
+        assert_eq!(
+            model_numbers(&[]),
+            Err("expected 14 blocks, one per digit, found 0".to_string())
+        );
+        let long = parse(&monad(&[blocks(), blocks()].concat())).unwrap();
+        assert_eq!(
+            model_numbers(&long),
+            Err("expected 14 blocks, one per digit, found 28".to_string())
+        );
+    }
+
+    #[test]
+    fn day24_part1() {
+        let input = parse(&monad(&blocks())).unwrap();
+        assert_eq!(input.len(), 14 * 18);
+        assert_eq!(part1(&input), Ok(92915979999498));
+    }
+
+    #[test]
+    fn day24_part2() {
+        let input = parse(&monad(&blocks())).unwrap();
+        assert_eq!(part2(&input), Ok(21611513911181));
+    }
+
+    #[test]
+    fn day24_random() {
+        for seed in 0..20 {
+            let input = parse(&gen::input(24, seed, 1).unwrap()).unwrap();
+            let (largest, smallest) = model_numbers(&input).unwrap();
+            assert!(largest >= smallest);
+        }
+    }
+}
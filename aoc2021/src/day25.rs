@@ -0,0 +1,159 @@
+use aoc::{
+    grid::{Grid, Topology},
+    parse::ParseError,
+    render::{Color, Image},
+    solution::{Answer, Example, Solution},
+};
+
+#[aoc_generator(day25)]
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    let grid = Grid::parse(25, input, |line| {
+        line.chars()
+            .map(|(c, token)| match c {
+                'v' | '>' | '.' => Ok(c),
+                _ => Err(line.error(token, "expected `v`, `>` or `.`")),
+            })
+            .collect()
+    })?;
+    Ok(grid.with_topology(Topology::Torus))
+}
+
+#[aoc(day25, part1)]
+pub fn part1(input: &Grid<char>) -> usize {
+    let mut herds = input.clone();
+    let mut steps = 1;
+
+    while step(&mut herds) {
+        steps += 1;
+    }
+
+    steps
+}
+
+/// Draws the herds after `steps` steps, or once they stopped moving: the
+/// east-facing herd in orange and the south-facing herd in blue.
+pub fn snapshot(input: &Grid<char>, steps: Option<usize>) -> Image {
+    let mut herds = input.clone();
+    for _ in 0..steps.unwrap_or(usize::MAX) {
+        if !step(&mut herds) {
+            break;
+        }
+    }
+    herds.map(|&c| match c {
+        '>' => Color::rgb(240, 140, 40),
+        'v' => Color::rgb(60, 120, 230),
+        _ => Color::BLACK,
+    })
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(58)]
+    }
+
+    fn render(input: &Self::Input, step: Option<usize>) -> Option<Image> {
+        Some(snapshot(input, step))
+    }
+}
+
+/// Moves the east-facing herd and then the south-facing herd, returning
+/// whether any sea cucumber moved.
+fn step(herds: &mut Grid<char>) -> bool {
+    let east = move_herd(herds, '>', (0, 1));
+    let south = move_herd(herds, 'v', (1, 0));
+    east || south
+}
+
+fn move_herd(herds: &mut Grid<char>, herd: char, direction: (isize, isize)) -> bool {
+    let moves = herds
+        .iter()
+        .filter(|&(_, &cell)| cell == herd)
+        .filter_map(|(pos, _)| {
+            let next = herds.offset(pos, direction)?;
+            (herds[next] == '.').then_some((pos, next))
+        })
+        .collect::<Vec<_>>();
+
+    for &(from, to) in &moves {
+        herds[from] = '.';
+        herds[to] = herd;
+    }
+
+    !moves.is_empty()
+}
+
+static INPUT: &str = r#"v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>"#;
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::{
+        parse::mangle,
+        render::Color,
+        solution::{Answer, Solution},
+    };
+
+    use super::{parse, part1, snapshot, Day25, INPUT};
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(input.height(), 9);
+        assert_eq!(input.width(), 10);
+        assert_eq!(input.row(0).iter().collect::<String>(), "v...>>.vv>");
+        assert_eq!(input.row(8).iter().collect::<String>(), "....v..v.>");
+        assert_eq!(input.to_string(), INPUT);
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day25_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 58);
+    }
+
+    #[test]
+    fn day25_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(Day25::part2(&input), Ok(Answer::None));
+    }
+
+    #[test]
+    fn day25_snapshot() {
+        let input = parse(INPUT).unwrap();
+        let start = snapshot(&input, Some(0));
+        assert_eq!(start[(0, 0)], Color::rgb(60, 120, 230));
+        assert_eq!(start[(0, 4)], Color::rgb(240, 140, 40));
+        assert_eq!(start[(0, 1)], Color::BLACK);
+
+        assert_ne!(snapshot(&input, Some(1)), start);
+        assert_eq!(snapshot(&input, None), snapshot(&input, Some(57)));
+        assert_eq!(snapshot(&input, None), snapshot(&input, Some(1000)));
+        assert_ne!(snapshot(&input, None), snapshot(&input, Some(56)));
+    }
+}
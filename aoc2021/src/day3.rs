@@ -0,0 +1,151 @@
+use aoc::{
+    grid::Grid,
+    parse::ParseError,
+    solution::{Answer, Example, Solution},
+};
+use itertools::Itertools;
+
+#[aoc_generator(day3)]
+pub fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse_digits(3, input, 2)
+}
+
+#[aoc(day3, part1)]
+pub fn part1(input: &Grid<u32>) -> i32 {
+    let len = input.width();
+    let mut gamma: Vec<u32> = vec![0; len];
+    let mut epsil: Vec<u32> = vec![1; len];
+
+    for (i, column) in input.columns().enumerate() {
+        if column.sum::<u32>() * 2 > input.height() as u32 {
+            gamma[i] = 1;
+            epsil[i] = 0;
+        }
+    }
+
+    to_decimal(&gamma) * to_decimal(&epsil)
+}
+
+#[aoc(day3, part2)]
+pub fn part2(input: &Grid<u32>) -> Result<i32, String> {
+    let oxy = calc(input, 0)?;
+    let co2 = calc(input, 1)?;
+    Ok(to_decimal(oxy) * to_decimal(co2))
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        part2(input).map(Answer::from)
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(198).part2(230)]
+    }
+}
+
+fn to_decimal(binary: &[u32]) -> i32 {
+    binary
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0, |acc, (i, &elem)| acc + elem as i32 * 2_i32.pow(i as u32))
+}
+
+/// The rating left after filtering by the most common bits, or by the least
+/// common ones if `xor_bit` is 1. Numbers that appear more than once can tie
+/// to the end, leaving no single rating.
+fn calc(report: &Grid<u32>, xor_bit: u32) -> Result<&[u32], String> {
+    let mut report = report.rows().collect_vec();
+
+    for i in 0..report[0].len() {
+        if report.len() == 1 {
+            break;
+        }
+        // When every number has the same bit here there is no least common
+        // one, and the position does not narrow anything down.
+        let common_bit = most_common_at_position(&report, i) ^ xor_bit;
+        if report.iter().any(|bits| bits[i] == common_bit) {
+            report.retain(|bits| bits[i] == common_bit);
+        }
+    }
+    match report.as_slice() {
+        [bits] => Ok(bits),
+        _ => Err("the report repeats the number a rating narrows down to".to_string()),
+    }
+}
+
+fn most_common_at_position(report: &[&[u32]], i: usize) -> u32 {
+    (report.iter().filter(|line| line[i] == 1).count() * 2 >= report.len()) as u32
+}
+
+static INPUT: &str =
+    "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::parse::mangle;
+    use itertools::Itertools;
+
+    use super::{parse, part1, part2, INPUT};
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(
+            input.rows().map(|row| row.to_vec()).collect_vec(),
+            vec![
+                vec![0, 0, 1, 0, 0],
+                vec![1, 1, 1, 1, 0],
+                vec![1, 0, 1, 1, 0],
+                vec![1, 0, 1, 1, 1],
+                vec![1, 0, 1, 0, 1],
+                vec![0, 1, 1, 1, 1],
+                vec![0, 0, 1, 1, 1],
+                vec![1, 1, 1, 0, 0],
+                vec![1, 0, 0, 0, 0],
+                vec![1, 1, 0, 0, 1],
+                vec![0, 0, 0, 1, 0],
+                vec![0, 1, 0, 1, 0],
+            ]
+        );
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day3_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 198);
+    }
+
+    #[test]
+    fn day3_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), Ok(230));
+        // A single number is both ratings.
+        assert_eq!(part2(&parse("101").unwrap()), Ok(25));
+        assert_eq!(part2(&parse("100\n101").unwrap()), Ok(5 * 4));
+        assert_eq!(
+            part2(&parse("101\n101").unwrap()),
+            Err("the report repeats the number a rating narrows down to".to_string())
+        );
+        assert!(part2(&parse("011\n110\n110").unwrap()).is_err());
+    }
+}
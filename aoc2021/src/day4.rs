@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use aoc::{
+    parse::{blocks, Line, ParseError},
+    solution::{Answer, Example, Solution},
+};
+
+pub const BOARD_SIZE: usize = 5;
+
+#[aoc_generator(day4)]
+pub fn parse(input: &str) -> Result<(Vec<i32>, Vec<Board>), ParseError> {
+    let blocks = blocks(4, input);
+    let (numbers_block, board_blocks) = blocks
+        .split_first()
+        .ok_or_else(|| ParseError::end_of_input(4, input, "drawn numbers"))?;
+    let numbers = numbers_block
+        .iter()
+        .flat_map(|line| line.text.split(',').map(move |n| line.parse(n)))
+        .collect::<Result<Vec<i32>, _>>()?;
+    let boards = board_blocks
+        .iter()
+        .map(|block| parse_board(block))
+        .collect::<Result<Vec<Board>, _>>()?;
+
+    Ok((numbers, boards))
+}
+
+/// A board from its rows of whitespace-separated numbers.
+fn parse_board(rows: &[Line]) -> Result<Board, ParseError> {
+    if rows.len() != BOARD_SIZE {
+        return Err(rows[0].error(rows[0].text, format!("expected {} rows", BOARD_SIZE)));
+    }
+
+    let mut numbers = [[0; BOARD_SIZE]; BOARD_SIZE];
+    for (row, numbers) in rows.iter().zip(&mut numbers) {
+        let cells = row.text.split_whitespace().collect::<Vec<_>>();
+        if cells.len() != BOARD_SIZE {
+            return Err(row.error(row.text, format!("expected {} numbers", BOARD_SIZE)));
+        }
+        for (cell, number) in cells.into_iter().zip(numbers) {
+            *number = row.parse(cell)?;
+        }
+    }
+    Ok(Board::new(numbers))
+}
+
+#[aoc(day4, part1)]
+pub fn part1((numbers, boards): &(Vec<i32>, Vec<Board>)) -> i32 {
+    let mut boards: Vec<Board> = boards.to_vec();
+
+    for n in numbers {
+        for board in boards.iter_mut() {
+            board.process_number(*n);
+            if board.has_won {
+                return board.calc_score(*n);
+            }
+        }
+    }
+
+    unreachable!();
+}
+
+#[aoc(day4, part2)]
+pub fn part2((numbers, boards): &(Vec<i32>, Vec<Board>)) -> i32 {
+    let mut boards: Vec<Board> = boards.to_vec();
+    let mut last_winning_score = 0;
+
+    for n in numbers {
+        for board in boards.iter_mut() {
+            if board.has_won {
+                continue;
+            }
+
+            board.process_number(*n);
+            if board.has_won {
+                last_winning_score = board.calc_score(*n);
+            }
+        }
+    }
+
+    last_winning_score
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+
+    type Input = (Vec<i32>, Vec<Board>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(4512).part2(1924)]
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Board {
+    numbers: HashMap<i32, (usize, usize)>,
+    rows_filled: Vec<usize>,
+    columns_filled: Vec<usize>,
+    has_won: bool,
+}
+
+impl Board {
+    /// A board with nothing marked yet, from its rows of numbers.
+    pub fn new(rows: [[i32; BOARD_SIZE]; BOARD_SIZE]) -> Board {
+        let mut numbers = HashMap::new();
+        for (i, row) in rows.into_iter().enumerate() {
+            for (j, number) in row.into_iter().enumerate() {
+                numbers.insert(number, (i, j));
+            }
+        }
+
+        Board {
+            numbers,
+            rows_filled: vec![0; BOARD_SIZE],
+            columns_filled: vec![0; BOARD_SIZE],
+            has_won: false,
+        }
+    }
+
+    /// Marks `n` if it is on the board.
+    pub fn process_number(&mut self, n: i32) {
+        if let Some((i, j)) = self.numbers.remove(&n) {
+            self.rows_filled[i] += 1;
+            self.columns_filled[j] += 1;
+        }
+        self.has_won = self.rows_filled.contains(&5) || self.columns_filled.contains(&5);
+    }
+
+    pub fn has_won(&self) -> bool {
+        self.has_won
+    }
+
+    /// The sum of the unmarked numbers times the number called last.
+    pub fn calc_score(&self, last_called: i32) -> i32 {
+        let unmarked_sum: i32 = self.numbers.keys().sum();
+        unmarked_sum * last_called
+    }
+}
+
+static INPUT: &str = r#"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7"#;
+
+#[cfg(test)]
+pub mod tests {
+    use std::{collections::HashMap, vec};
+
+    use aoc::parse::mangle;
+
+    use crate::day4::BOARD_SIZE;
+
+    use super::{parse, part1, part2, Board, INPUT};
+
+    #[test]
+    fn generator() {
+        let (numbers, boards) = parse(INPUT).unwrap();
+        let exp_numbers = vec![
+            7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8, 19,
+            3, 26, 1,
+        ];
+        let exp_board = Board {
+            numbers: HashMap::from([
+                (22, (0, 0)),
+                (13, (0, 1)),
+                (17, (0, 2)),
+                (11, (0, 3)),
+                (0, (0, 4)),
+                (8, (1, 0)),
+                (2, (1, 1)),
+                (23, (1, 2)),
+                (4, (1, 3)),
+                (24, (1, 4)),
+                (21, (2, 0)),
+                (9, (2, 1)),
+                (14, (2, 2)),
+                (16, (2, 3)),
+                (7, (2, 4)),
+                (6, (3, 0)),
+                (10, (3, 1)),
+                (3, (3, 2)),
+                (18, (3, 3)),
+                (5, (3, 4)),
+                (1, (4, 0)),
+                (12, (4, 1)),
+                (20, (4, 2)),
+                (15, (4, 3)),
+                (19, (4, 4)),
+            ]),
+            rows_filled: vec![0; BOARD_SIZE],
+            columns_filled: vec![0; BOARD_SIZE],
+            has_won: false,
+        };
+        assert_eq!(numbers, exp_numbers);
+        assert_eq!(boards[0], exp_board);
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day4_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 4512);
+    }
+
+    #[test]
+    fn day4_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 1924);
+    }
+}
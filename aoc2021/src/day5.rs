@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use aoc::{
+    grid::Grid,
+    parse::{lines, Line, ParseError},
+    render::{Color, Image},
+    solution::{Answer, Example, Solution},
+};
+use itertools::{Itertools, MinMaxResult};
+
+#[aoc_generator(day5)]
+pub fn parse(input: &str) -> Result<Vec<(Point, Point)>, ParseError> {
+    lines(5, input)
+        .map(|line| {
+            let (p1, p2) = line.split_once(line.text, " -> ")?;
+            Ok((Point::parse(&line, p1)?, Point::parse(&line, p2)?))
+        })
+        .collect()
+}
+
+#[aoc(day5, part1)]
+pub fn part1(input: &[(Point, Point)]) -> usize {
+    let mut map: Map = HashMap::new();
+
+    for (p1, p2) in input {
+        if p1.x != p2.x && p1.y != p2.y {
+            continue;
+        }
+        traverse_vent(&mut map, (p1, p2));
+    }
+
+    map.values().filter(|&&v| v >= 2).count()
+}
+
+#[aoc(day5, part2)]
+pub fn part2(input: &[(Point, Point)]) -> usize {
+    let mut map: Map = HashMap::new();
+
+    for (p1, p2) in input {
+        traverse_vent(&mut map, (p1, p2));
+    }
+
+    map.values().filter(|&&v| v >= 2).count()
+}
+
+/// Colours every point by how many vents cover it once the first `step`
+/// vents (all of them by default) are drawn, diagonals included.
+pub fn heatmap(input: &[(Point, Point)], step: Option<usize>) -> Image {
+    let mut map: Map = HashMap::new();
+    for (p1, p2) in input.iter().take(step.unwrap_or(input.len())) {
+        traverse_vent(&mut map, (p1, p2));
+    }
+
+    // Size the image for all vents, so every step has the same dimensions.
+    let points = input.iter().flat_map(|(p1, p2)| [p1, p2]);
+    let bounds = |coords: MinMaxResult<i32>| match coords {
+        MinMaxResult::NoElements => (0, 0),
+        MinMaxResult::OneElement(c) => (c, c),
+        MinMaxResult::MinMax(min, max) => (min, max),
+    };
+    let (min_x, max_x) = bounds(points.clone().map(|p| p.x).minmax());
+    let (min_y, max_y) = bounds(points.map(|p| p.y).minmax());
+    let max = map.values().max().copied().unwrap_or(0);
+
+    Grid::from_fn(
+        (max_x - min_x + 1) as usize,
+        (max_y - min_y + 1) as usize,
+        |(row, col)| match map.get(&(min_x + col as i32, min_y + row as i32)) {
+            Some(&count) => Color::heat(count as f64, max as f64),
+            None => Color::BLACK,
+        },
+    )
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type Input = Vec<(Point, Point)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(5).part2(12)]
+    }
+
+    fn render(input: &Self::Input, step: Option<usize>) -> Option<Image> {
+        Some(heatmap(input, step))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn parse(line: &Line, token: &str) -> Result<Point, ParseError> {
+        let (x, y) = line.split_once(token, ",")?;
+        Ok(Point {
+            x: line.parse(x)?,
+            y: line.parse(y)?,
+        })
+    }
+}
+
+type Map = HashMap<(i32, i32), i32>;
+
+fn traverse_vent(map: &mut Map, (p1, p2): (&Point, &Point)) {
+    let dx = p1.x - p2.x;
+    let dy = p1.y - p2.y;
+    let direction_x = get_direction(dx);
+    let direction_y = get_direction(dy);
+    let distance = dx.abs().max(dy.abs());
+
+    for i in 0..=distance {
+        let point = (p1.x + i * direction_x, p1.y + i * direction_y);
+        *map.entry(point).or_insert(0) += 1;
+    }
+}
+
+fn get_direction(distance: i32) -> i32 {
+    match distance.cmp(&0) {
+        std::cmp::Ordering::Less => 1,
+        std::cmp::Ordering::Equal => 0,
+        std::cmp::Ordering::Greater => -1,
+    }
+}
+
+static INPUT: &str = r#"0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2"#;
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::{parse::mangle, render::Color};
+
+    use super::{heatmap, parse, part1, part2, Point, INPUT};
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(input[0], (Point { x: 0, y: 9 }, Point { x: 5, y: 9 }));
+        assert_eq!(input[1], (Point { x: 8, y: 0 }, Point { x: 0, y: 8 }));
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day5_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 5);
+    }
+
+    #[test]
+    fn day5_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 12);
+    }
+
+    #[test]
+    fn day5_heatmap() {
+        let input = parse(INPUT).unwrap();
+        let image = heatmap(&input, None);
+        assert_eq!((image.width(), image.height()), (10, 10));
+        assert_eq!(image[(4, 4)], Color::heat(3.0, 3.0));
+        assert_eq!(image[(4, 3)], Color::heat(2.0, 3.0));
+        assert_eq!(image[(0, 1)], Color::BLACK);
+
+        let first = heatmap(&input, Some(1));
+        assert_eq!((first.width(), first.height()), (10, 10));
+        assert_eq!(first[(9, 0)], Color::heat(1.0, 1.0));
+        assert_eq!(first[(4, 4)], Color::BLACK);
+    }
+}
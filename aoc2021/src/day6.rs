@@ -0,0 +1,145 @@
+use aoc::{
+    parse::{lines, ParseError},
+    solution::{Answer, Example, Solution},
+};
+
+#[aoc_generator(day6)]
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    lines(6, input)
+        .flat_map(|line| {
+            line.text
+                .split(',')
+                .map(move |timer| match line.parse(timer)? {
+                    t @ 0..=8 => Ok(t),
+                    _ => Err(line.error(timer, "expected a timer between 0 and 8")),
+                })
+        })
+        .collect()
+}
+
+#[aoc(day6, part1)]
+pub fn part1(input: &[i32]) -> usize {
+    populate(input, 80)
+}
+
+#[aoc(day6, part2)]
+pub fn part2(input: &[i32]) -> usize {
+    populate(input, 256)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(5934).part2(26984457539i64)]
+    }
+}
+
+fn populate(input: &[i32], days: usize) -> usize {
+    let mut age_counts = [0usize; 9];
+
+    for &f in input {
+        age_counts[f as usize] += 1;
+    }
+
+    for _ in 0..days {
+        age_counts.rotate_left(1);
+        age_counts[6] += age_counts[8];
+    }
+
+    age_counts.iter().sum::<usize>()
+}
+
+static INPUT: &str = "3,4,3,1,2";
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::{
+        diff::{check, shrink_vec},
+        parse::mangle,
+    };
+
+    use super::{parse, part1, part2, populate, INPUT};
+    use crate::gen;
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(input, vec![3, 4, 3, 1, 2]);
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day6_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 5934);
+    }
+
+    #[test]
+    fn day6_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 26984457539);
+    }
+
+    /// Simulates every fish on its own.
+    fn simulate(input: &[i32], days: usize) -> usize {
+        let mut fish = input.to_vec();
+        for _ in 0..days {
+            for i in 0..fish.len() {
+                if fish[i] == 0 {
+                    fish[i] = 6;
+                    fish.push(8);
+                } else {
+                    fish[i] -= 1;
+                }
+            }
+        }
+        fish.len()
+    }
+
+    #[test]
+    fn day6_differential() {
+        let result = check(
+            200,
+            |rng| {
+                let fish = gen::input(6, rng.next_u64(), rng.range(1..=8) as usize).unwrap();
+                (parse(&fish).unwrap(), rng.range(0..=60) as usize)
+            },
+            |(fish, days)| {
+                let mut smaller = shrink_vec(fish, |&timer| (0..timer).collect())
+                    .into_iter()
+                    .map(|fish| (fish, *days))
+                    .collect::<Vec<_>>();
+                if *days > 0 {
+                    let mut shorter = vec![days / 2, days - 1];
+                    shorter.dedup();
+                    smaller.extend(shorter.into_iter().map(|d| (fish.clone(), d)));
+                }
+                smaller
+            },
+            |(fish, days)| simulate(fish, *days),
+            |(fish, days)| populate(fish, *days),
+        );
+        assert_eq!(result, Ok(()));
+    }
+}
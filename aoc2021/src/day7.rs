@@ -0,0 +1,100 @@
+use aoc::{
+    parse::{lines, ParseError},
+    solution::{Answer, Example, Solution},
+};
+use itertools::Itertools;
+
+#[aoc_generator(day7)]
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    lines(7, input)
+        .flat_map(|line| line.text.split(',').map(move |pos| line.parse(pos)))
+        .collect()
+}
+
+#[aoc(day7, part1)]
+pub fn part1(input: &[i32]) -> i32 {
+    let desired_pos = input.iter().sorted_unstable().nth(input.len() / 2).unwrap();
+    calculate_fuel_p1(input, *desired_pos)
+}
+
+#[aoc(day7, part2)]
+pub fn part2(input: &[i32]) -> i32 {
+    let &min = input.iter().min().unwrap();
+    let &max = input.iter().max().unwrap();
+
+    (min..=max).fold(i32::MAX, |acc, desired_pos| {
+        acc.min(calculate_fuel_p2(input, desired_pos))
+    })
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(37).part2(168)]
+    }
+}
+
+fn calculate_fuel_p1(input: &[i32], desired_pos: i32) -> i32 {
+    input
+        .iter()
+        .fold(0, |acc, pos| acc + (pos - desired_pos).abs())
+}
+
+fn calculate_fuel_p2(input: &[i32], desired_pos: i32) -> i32 {
+    input.iter().fold(0, |acc, pos| {
+        acc + nth_triangular((pos - desired_pos).abs())
+    })
+}
+
+fn nth_triangular(n: i32) -> i32 {
+    n * (n + 1) / 2
+}
+
+static INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::parse::mangle;
+
+    use super::{parse, part1, part2, INPUT};
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(input, vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14]);
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day7_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 37);
+    }
+
+    #[test]
+    fn day7_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 168);
+    }
+}
@@ -1,13 +1,18 @@
 use itertools::Itertools;
 use std::collections::HashMap;
 
+use aoc::{
+    parse::{lines, Line, ParseError},
+    solution::{Answer, Example, Solution},
+};
+
 #[aoc_generator(day8)]
-fn generator_input(input: &str) -> Vec<Entry> {
-    input.lines().map(|a| Entry::from_str(a)).collect_vec()
+pub fn parse(input: &str) -> Result<Vec<Entry>, ParseError> {
+    lines(8, input).map(|line| Entry::parse(&line)).collect()
 }
 
 #[aoc(day8, part1)]
-fn part1(input: &[Entry]) -> usize {
+pub fn part1(input: &[Entry]) -> usize {
     input.iter().fold(0, |acc, e| {
         acc + e
             .output
@@ -18,10 +23,34 @@ fn part1(input: &[Entry]) -> usize {
 }
 
 #[aoc(day8, part2)]
-fn part2(input: &[Entry]) -> i32 {
+pub fn part2(input: &[Entry]) -> i32 {
     input.iter().map(decode).sum()
 }
 
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    type Input = Vec<Entry>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(26).part2(61229)]
+    }
+}
+
 fn decode(entry: &Entry) -> i32 {
     let histogram = build_histogram(&entry.signal);
     decode_output(&histogram, &entry.output)
@@ -56,31 +85,38 @@ fn decode_output(histogram: &HashMap<char, i32>, output: &[String]) -> i32 {
         .fold(0, |acc, digit| acc * 10 + digit)
 }
 
-struct Entry {
+#[derive(Debug, PartialEq)]
+pub struct Entry {
     signal: Vec<String>,
     output: Vec<String>,
 }
 
 impl Entry {
-    fn from_str(input: &str) -> Self {
-        let (signal_raw, output_raw) = input.split_once(" | ").unwrap();
-        let signal = signal_raw
-            .split_whitespace()
-            .map(|s| s.to_string())
-            .collect_vec();
-        let output = output_raw
-            .split_whitespace()
-            .map(|s| s.to_string())
-            .collect_vec();
-        Entry { signal, output }
+    fn parse(line: &Line) -> Result<Self, ParseError> {
+        let (signal_raw, output_raw) = line.split_once(line.text, " | ")?;
+        let signal = Self::parse_patterns(line, signal_raw, 10)?;
+        let output = Self::parse_patterns(line, output_raw, 4)?;
+        Ok(Entry { signal, output })
     }
-}
 
-#[cfg(test)]
-pub mod tests {
-    use super::{generator_input, part1, part2};
+    fn parse_patterns(line: &Line, raw: &str, count: usize) -> Result<Vec<String>, ParseError> {
+        let patterns = raw.split_whitespace().collect_vec();
+        if patterns.len() != count {
+            return Err(line.error(raw, format!("expected {} patterns", count)));
+        }
+        patterns
+            .into_iter()
+            .map(
+                |pattern| match pattern.chars().all(|c| ('a'..='g').contains(&c)) {
+                    true => Ok(pattern.to_string()),
+                    false => Err(line.error(pattern, "expected segments `a` to `g`")),
+                },
+            )
+            .collect()
+    }
+}
 
-    static INPUT: &str = r#"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+static INPUT: &str = r#"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
 edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
 fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
 fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
@@ -91,9 +127,15 @@ bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbg
 egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
 gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"#;
 
+#[cfg(test)]
+pub mod tests {
+    use aoc::parse::mangle;
+
+    use super::{parse, part1, part2, INPUT};
+
     #[test]
     fn generator() {
-        let input = generator_input(INPUT);
+        let input = parse(INPUT).unwrap();
         assert_eq!(input.len(), 10);
         assert_eq!(
             input[0].signal,
@@ -113,15 +155,20 @@ gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
         assert_eq!(input[9].output, vec!["fgae", "cfgab", "fg", "bagce"]);
     }
 
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
     #[test]
     fn day8_part1() {
-        let input = generator_input(INPUT);
+        let input = parse(INPUT).unwrap();
         assert_eq!(part1(&input), 26);
     }
 
     #[test]
     fn day8_part2() {
-        let input = generator_input(INPUT);
+        let input = parse(INPUT).unwrap();
         assert_eq!(part2(&input), 61229);
     }
 }
@@ -0,0 +1,187 @@
+use std::collections::HashSet;
+
+use aoc::{
+    grid::{Grid, Pos},
+    parse::ParseError,
+    render::{Color, Image},
+    solution::{Answer, Example, Solution},
+};
+use itertools::Itertools;
+
+#[aoc_generator(day9)]
+pub fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse_digits(9, input, 10)
+}
+
+#[aoc(day9, part1)]
+pub fn part1(input: &Grid<u32>) -> u32 {
+    let low_coords = find_low_coords(input);
+    low_coords.iter().map(|&pos| input[pos]).sum::<u32>() + low_coords.len() as u32
+}
+
+#[aoc(day9, part2)]
+pub fn part2(input: &Grid<u32>) -> usize {
+    let low_coords = find_low_coords(input);
+    let mut basin_sizes = low_coords
+        .iter()
+        .map(|&pos| 1 + flood_neighbors(input, &mut HashSet::from([pos]), pos))
+        .collect_vec();
+
+    basin_sizes.sort_unstable();
+    basin_sizes.reverse();
+
+    // Small maps may have fewer than three basins.
+    basin_sizes.iter().take(3).product()
+}
+
+/// Colours every basin by its id, with the walls of height 9 in black and
+/// the low points in white.
+pub fn basin_map(input: &Grid<u32>) -> Image {
+    let mut ids = input.map(|_| None);
+    let mut basins = 0;
+    for pos in input.positions() {
+        if input[pos] == 9 || ids[pos].is_some() {
+            continue;
+        }
+        ids[pos] = Some(basins);
+        let mut stack = vec![pos];
+        while let Some(pos) = stack.pop() {
+            for neighbor in input.neighbors4(pos) {
+                if input[neighbor] != 9 && ids[neighbor].is_none() {
+                    ids[neighbor] = Some(basins);
+                    stack.push(neighbor);
+                }
+            }
+        }
+        basins += 1;
+    }
+
+    let low_coords = find_low_coords(input);
+    Grid::from_fn(input.width(), input.height(), |pos| match ids[pos] {
+        _ if low_coords.contains(&pos) => Color::WHITE,
+        Some(id) => Color::id(id),
+        None => Color::BLACK,
+    })
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT).part1(15).part2(1134)]
+    }
+
+    fn render(input: &Self::Input, _step: Option<usize>) -> Option<Image> {
+        Some(basin_map(input))
+    }
+}
+
+fn find_low_coords(input: &Grid<u32>) -> Vec<Pos> {
+    input
+        .positions()
+        .filter(|&pos| is_low(input, pos))
+        .collect()
+}
+
+fn is_low(input: &Grid<u32>, pos: Pos) -> bool {
+    input
+        .neighbors4(pos)
+        .all(|neighbor| input[pos] < input[neighbor])
+}
+
+fn flood_neighbors(input: &Grid<u32>, flooded_coords: &mut HashSet<Pos>, pos: Pos) -> usize {
+    let height = input[pos];
+    let mut size = 0;
+
+    for neighbor in input.neighbors4(pos) {
+        if flooded_coords.contains(&neighbor) {
+            continue;
+        }
+
+        let n_height = input[neighbor];
+        if n_height > height && n_height != 9 {
+            flooded_coords.insert(neighbor);
+            size += flood_neighbors(input, flooded_coords, neighbor);
+            size += 1;
+        }
+    }
+
+    size
+}
+
+static INPUT: &str = r#"2199943210
+3987894921
+9856789892
+8767896789
+9899965678"#;
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::HashSet;
+
+    use aoc::{parse::mangle, render::Color};
+    use itertools::Itertools;
+
+    use super::{basin_map, parse, part1, part2, INPUT};
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(
+            input.rows().map(|row| row.to_vec()).collect_vec(),
+            vec![
+                vec![2, 1, 9, 9, 9, 4, 3, 2, 1, 0],
+                vec![3, 9, 8, 7, 8, 9, 4, 9, 2, 1],
+                vec![9, 8, 5, 6, 7, 8, 9, 8, 9, 2],
+                vec![8, 7, 6, 7, 8, 9, 6, 7, 8, 9],
+                vec![9, 8, 9, 9, 9, 6, 5, 6, 7, 8],
+            ]
+        );
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn day9_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 15);
+    }
+
+    #[test]
+    fn day9_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 1134);
+    }
+
+    #[test]
+    fn day9_basin_map() {
+        let input = parse(INPUT).unwrap();
+        let image = basin_map(&input);
+        assert_eq!(image[(0, 0)], image[(1, 0)]);
+        assert_ne!(image[(0, 0)], image[(0, 5)]);
+        assert_eq!(image[(0, 1)], Color::WHITE);
+        assert_eq!(image[(0, 2)], Color::BLACK);
+
+        let colors = image.values().collect::<HashSet<_>>();
+        assert_eq!(colors.len(), 4 + 2);
+    }
+}
@@ -0,0 +1,75 @@
+use aoc::{
+    parse::{lines, ParseError},
+    solution::{Answer, Example, Solution},
+};
+
+#[aoc_generator(dayX)]
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    lines(X, input).map(|line| line.parse(line.text)).collect()
+}
+
+#[aoc(dayX, part1)]
+pub fn part1(_input: &[i32]) -> i32 {
+    0
+}
+
+#[aoc(dayX, part2)]
+pub fn part2(_input: &[i32]) -> i32 {
+    0
+}
+
+pub struct DayX;
+
+impl Solution for DayX {
+    const DAY: u32 = X;
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(input).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(INPUT)]
+    }
+}
+
+static INPUT: &str = r#""#;
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::parse::mangle;
+
+    use super::{parse, part1, part2, INPUT};
+
+    #[test]
+    fn generator() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(input, Vec::<i32>::new());
+    }
+
+    #[test]
+    fn generator_mangled() {
+        assert_eq!(parse(&mangle(INPUT)), parse(INPUT));
+    }
+
+    #[test]
+    fn dayX_part1() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part1(&input), 0);
+    }
+
+    #[test]
+    fn dayX_part2() {
+        let input = parse(INPUT).unwrap();
+        assert_eq!(part2(&input), 0);
+    }
+}
@@ -0,0 +1,700 @@
+//! Random but structurally valid puzzle inputs, for stress tests that need
+//! inputs larger or stranger than the official ones.
+//!
+//! Everything is derived from a seed, so the same seed and scale always give
+//! the same input.
+
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use aoc::rng::Rng;
+use itertools::Itertools;
+
+use crate::day24::{self, Block};
+
+/// Generates an input for `day` at the given scale, or `None` if the day has
+/// no generator. What the scale counts depends on the day (lines, grid side,
+/// packets, ...), see the per-day functions below.
+pub fn input(day: u32, seed: u64, scale: usize) -> Option<String> {
+    let rng = &mut Rng::new(seed);
+    let scale = scale.max(1);
+    let input = match day {
+        1 => day1(rng, scale),
+        2 => day2(rng, scale),
+        3 => day3(rng, scale),
+        4 => day4(rng, scale),
+        5 => day5(rng, scale),
+        6 => day6(rng, scale),
+        7 => day7(rng, scale),
+        8 => day8(rng, scale),
+        9 => day9(rng, scale),
+        10 => day10(rng, scale),
+        11 => day11(rng, scale),
+        12 => day12(rng, scale),
+        13 => day13(rng, scale),
+        14 => day14(rng, scale),
+        15 => day15(rng, scale),
+        16 => day16(rng, scale),
+        17 => day17(rng, scale),
+        18 => day18(rng, scale),
+        19 => day19(rng, scale),
+        20 => day20(rng, scale),
+        21 => day21(rng, scale),
+        22 => day22(rng, scale),
+        23 => day23(rng, scale),
+        24 => day24(rng),
+        25 => day25(rng, scale),
+        _ => return None,
+    };
+    Some(input)
+}
+
+fn digit_grid(rng: &mut Rng, side: usize, digits: RangeInclusive<i64>) -> String {
+    (0..side)
+        .map(|_| (0..side).map(|_| rng.range(digits.clone())).join(""))
+        .join("\n")
+}
+
+/// `scale` depth measurements.
+fn day1(rng: &mut Rng, scale: usize) -> String {
+    let mut depth = rng.range(100..=200);
+    (0..scale)
+        .map(|_| {
+            depth = (depth + rng.range(-20..=30)).max(0);
+            depth
+        })
+        .join("\n")
+}
+
+/// `scale` commands, never rising above the surface.
+fn day2(rng: &mut Rng, scale: usize) -> String {
+    let mut depth = 0;
+    (0..scale)
+        .map(|_| {
+            let x = rng.range(1..=9);
+            match rng.below(3) {
+                0 if depth >= x => {
+                    depth -= x;
+                    format!("up {}", x)
+                }
+                1 => {
+                    depth += x;
+                    format!("down {}", x)
+                }
+                _ => format!("forward {}", x),
+            }
+        })
+        .join("\n")
+}
+
+/// `scale` distinct binary numbers of at least 12 bits.
+fn day3(rng: &mut Rng, scale: usize) -> String {
+    let mut bits = 12;
+    while scale > 1 << (bits - 1) {
+        bits += 1;
+    }
+    let mut numbers = HashSet::new();
+    while numbers.len() < scale {
+        numbers.insert(rng.below(1 << bits));
+    }
+    let mut numbers = numbers.into_iter().sorted().collect_vec();
+    rng.shuffle(&mut numbers);
+    numbers
+        .iter()
+        .map(|n| format!("{:0width$b}", n, width = bits))
+        .join("\n")
+}
+
+/// `scale` boards. Every number is drawn, so every board wins eventually.
+fn day4(rng: &mut Rng, scale: usize) -> String {
+    let mut numbers = (0..100).collect_vec();
+    rng.shuffle(&mut numbers);
+    let mut input = numbers.iter().join(",");
+    for _ in 0..scale {
+        rng.shuffle(&mut numbers);
+        input += "\n";
+        for row in numbers[..25].chunks(5) {
+            input += &format!("\n{}", row.iter().map(|n| format!("{:>2}", n)).join(" "));
+        }
+    }
+    input
+}
+
+/// `scale` horizontal, vertical or diagonal lines.
+fn day5(rng: &mut Rng, scale: usize) -> String {
+    let size = (scale as i64 * 2).max(10);
+    (0..scale)
+        .map(|_| {
+            let (x1, y1) = (rng.range(0..=size - 1), rng.range(0..=size - 1));
+            let (dx, dy) = *rng.choose(&[(1, 0), (0, 1), (1, 1), (1, -1)]);
+            let sign = *rng.choose(&[-1, 1]);
+            let fits = |from: i64, d: i64| match d * sign {
+                1 => size - 1 - from,
+                -1 => from,
+                _ => i64::MAX,
+            };
+            let length = rng.range(0..=fits(x1, dx).min(fits(y1, dy)).min(size / 2));
+            let (x2, y2) = (x1 + dx * sign * length, y1 + dy * sign * length);
+            format!("{},{} -> {},{}", x1, y1, x2, y2)
+        })
+        .join("\n")
+}
+
+/// `scale` fish.
+fn day6(rng: &mut Rng, scale: usize) -> String {
+    (0..scale).map(|_| rng.range(1..=5)).join(",")
+}
+
+/// `scale` crabs.
+fn day7(rng: &mut Rng, scale: usize) -> String {
+    (0..scale)
+        .map(|_| rng.range(0..=scale as i64 * 2))
+        .join(",")
+}
+
+/// `scale` displays, each wired up with its own random permutation.
+fn day8(rng: &mut Rng, scale: usize) -> String {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+    (0..scale)
+        .map(|_| {
+            let mut wires = ('a'..='g').collect_vec();
+            rng.shuffle(&mut wires);
+            let pattern = |rng: &mut Rng, digit: usize| {
+                let mut segments = DIGITS[digit]
+                    .chars()
+                    .map(|c| wires[c as usize - 'a' as usize])
+                    .collect_vec();
+                rng.shuffle(&mut segments);
+                segments.into_iter().collect::<String>()
+            };
+            let mut signal = (0..10).collect_vec();
+            rng.shuffle(&mut signal);
+            let signal = signal.into_iter().map(|d| pattern(rng, d)).join(" ");
+            let output = (0..4)
+                .map(|_| {
+                    let digit = rng.below(10) as usize;
+                    pattern(rng, digit)
+                })
+                .join(" ");
+            format!("{} | {}", signal, output)
+        })
+        .join("\n")
+}
+
+/// A `scale` by `scale` height map.
+fn day9(rng: &mut Rng, scale: usize) -> String {
+    digit_grid(rng, scale, 0..=9)
+}
+
+/// `scale` lines, about half of them corrupted and the rest incomplete.
+fn day10(rng: &mut Rng, scale: usize) -> String {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    (0..scale)
+        .map(|_| {
+            let length = rng.range(4..=60) as usize;
+            let corrupt_at = rng.chance(0.5).then(|| rng.below(length as u64) as usize);
+            let mut line = String::new();
+            let mut open = vec![];
+            for i in 0..length {
+                if open.is_empty() || rng.chance(0.55) {
+                    let &(opening, closing) = rng.choose(&PAIRS);
+                    line.push(opening);
+                    open.push(closing);
+                } else {
+                    let closing = open.pop().unwrap();
+                    if corrupt_at.is_some_and(|at| i >= at) {
+                        let wrong = PAIRS.iter().map(|p| p.1).filter(|&c| c != closing);
+                        line.push(*rng.choose(&wrong.collect_vec()));
+                        break;
+                    }
+                    line.push(closing);
+                }
+            }
+            if open.is_empty() {
+                line.push('(');
+            }
+            line
+        })
+        .join("\n")
+}
+
+/// A `scale` by `scale` grid of octopuses.
+fn day11(rng: &mut Rng, scale: usize) -> String {
+    digit_grid(rng, scale, 0..=9)
+}
+
+/// A connected cave system with `scale` small caves and about a third as
+/// many big ones. Big caves are never linked directly, so the number of
+/// paths stays finite.
+fn day12(rng: &mut Rng, scale: usize) -> String {
+    // Leaving out `e` and `s` keeps the names clear of `end` and `start`.
+    const LETTERS: &[u8] = b"abcdfghijklmnopqrtuvwxyz";
+    let name = |mut i: usize, big: bool| {
+        let mut name = String::new();
+        loop {
+            let c = LETTERS[i % LETTERS.len()] as char;
+            name.push(if big { c.to_ascii_uppercase() } else { c });
+            i /= LETTERS.len();
+            if i == 0 {
+                return name;
+            }
+            i -= 1;
+        }
+    };
+
+    let mut caves = (0..scale)
+        .map(|i| (name(i, false), false))
+        .chain((0..scale / 3 + 1).map(|i| (name(i, true), true)))
+        .collect_vec();
+    rng.shuffle(&mut caves);
+    let first_small = caves.iter().position(|&(_, big)| !big).unwrap();
+    caves.swap(0, first_small);
+
+    let mut edges = HashSet::new();
+    let link = |edges: &mut HashSet<(usize, usize)>, a: usize, b: usize| {
+        (a != b && !(caves[a].1 && caves[b].1)).then(|| edges.insert((a.min(b), a.max(b))))
+    };
+    // A random spanning tree first, so every cave is reachable and has a
+    // neighbour besides `start` and `end`.
+    for i in 1..caves.len() {
+        while link(&mut edges, i, rng.below(i as u64) as usize).is_none() {}
+    }
+    for _ in 0..scale / 2 {
+        let a = rng.below(caves.len() as u64) as usize;
+        let b = rng.below(caves.len() as u64) as usize;
+        link(&mut edges, a, b);
+    }
+    let n = caves.len();
+    for (cave, links) in [("start", 3), ("end", 2)] {
+        for _ in 0..links {
+            edges.insert((rng.below(n as u64) as usize, caves.len()));
+        }
+        caves.push((cave.to_string(), false));
+    }
+
+    let mut edges = edges.into_iter().sorted().collect_vec();
+    rng.shuffle(&mut edges);
+    edges
+        .into_iter()
+        .map(|(a, b)| match rng.chance(0.5) {
+            true => format!("{}-{}", caves[a].0, caves[b].0),
+            false => format!("{}-{}", caves[b].0, caves[a].0),
+        })
+        .join("\n")
+}
+
+/// About `scale` dots that fold up into a 40 by 6 code after 12 folds.
+fn day13(rng: &mut Rng, scale: usize) -> String {
+    let (mut width, mut height) = (40, 6);
+    let mut dots = (0..scale)
+        .map(|_| (rng.range(0..=width - 1), rng.range(0..=height - 1)))
+        .collect::<HashSet<_>>();
+
+    // Unfold the paper again, mirroring each dot onto a random side.
+    let mut folds = vec![];
+    for _ in 0..12 {
+        let along_x = rng.chance(0.5);
+        let line = if along_x { width } else { height };
+        dots = dots
+            .into_iter()
+            .map(|(x, y)| match (along_x, rng.chance(0.5)) {
+                (true, true) => (2 * line - x, y),
+                (false, true) => (x, 2 * line - y),
+                _ => (x, y),
+            })
+            .collect();
+        if along_x {
+            width = 2 * width + 1;
+        } else {
+            height = 2 * height + 1;
+        }
+        folds.push(format!(
+            "fold along {}={}",
+            if along_x { 'x' } else { 'y' },
+            line
+        ));
+    }
+
+    let mut dots = dots.into_iter().sorted().collect_vec();
+    rng.shuffle(&mut dots);
+    let dots = dots.iter().map(|(x, y)| format!("{},{}", x, y)).join("\n");
+    format!("{}\n\n{}", dots, folds.iter().rev().join("\n"))
+}
+
+/// A polymer of `scale` elements with rules for every pair of 10 elements.
+fn day14(rng: &mut Rng, scale: usize) -> String {
+    let mut elements = ('A'..='Z').collect_vec();
+    rng.shuffle(&mut elements);
+    elements.truncate(10);
+    let polymer = (0..scale)
+        .map(|_| *rng.choose(&elements))
+        .collect::<String>();
+    let rules = elements
+        .iter()
+        .cartesian_product(&elements)
+        .map(|(a, b)| format!("{}{} -> {}", a, b, rng.choose(&elements)))
+        .collect_vec();
+    format!("{}\n\n{}", polymer, rules.join("\n"))
+}
+
+/// A `scale` by `scale` risk map.
+fn day15(rng: &mut Rng, scale: usize) -> String {
+    digit_grid(rng, scale, 1..=9)
+}
+
+/// A transmission of `scale` randomly nested packets.
+fn day16(rng: &mut Rng, scale: usize) -> String {
+    let mut bits = packet(rng, scale);
+    while !bits.len().is_multiple_of(4) {
+        bits.push(false);
+    }
+    bits.chunks(4)
+        .map(|nibble| nibble.iter().fold(0, |n, &bit| (n << 1) | bit as u32))
+        .map(|n| std::char::from_digit(n, 16).unwrap().to_ascii_uppercase())
+        .collect()
+}
+
+fn packet(rng: &mut Rng, size: usize) -> Vec<bool> {
+    let mut bits = vec![];
+    let push = |bits: &mut Vec<bool>, value: u64, width: usize| {
+        bits.extend((0..width).rev().map(|i| value >> i & 1 == 1));
+    };
+    push(&mut bits, rng.below(8), 3);
+
+    if size == 1 {
+        push(&mut bits, 4, 3);
+        let groups = rng.range(1..=4) as usize;
+        for group in (0..groups).rev() {
+            push(&mut bits, (group > 0) as u64, 1);
+            push(&mut bits, rng.below(16), 4);
+        }
+        return bits;
+    }
+
+    // Comparisons take exactly two sub-packets, the others at least one.
+    let type_id = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+    let count = match type_id {
+        5..=7 if size >= 3 => 2,
+        5..=7 => return packet(rng, size),
+        _ => rng.range(1..=(size as i64 - 1).min(5)) as usize,
+    };
+    let mut sizes = vec![1; count];
+    for _ in count..size - 1 {
+        sizes[rng.below(count as u64) as usize] += 1;
+    }
+    let children = sizes
+        .into_iter()
+        .flat_map(|size| packet(rng, size))
+        .collect_vec();
+
+    push(&mut bits, type_id, 3);
+    if children.len() < 1 << 15 && rng.chance(0.5) {
+        push(&mut bits, 0, 1);
+        push(&mut bits, children.len() as u64, 15);
+    } else {
+        push(&mut bits, 1, 1);
+        push(&mut bits, count as u64, 11);
+    }
+    bits.extend(children);
+    bits
+}
+
+/// A target area roughly `scale` steps away.
+fn day17(rng: &mut Rng, scale: usize) -> String {
+    let scale = scale.max(10) as i64;
+    let x1 = rng.range(scale..=3 * scale);
+    let x2 = x1 + rng.range(0..=scale / 2);
+    let y1 = -rng.range(scale..=3 * scale);
+    let y2 = y1 + rng.range(0..=scale / 2);
+    format!("target area: x={}..{}, y={}..{}", x1, x2, y1, y2)
+}
+
+/// `scale` reduced snailfish numbers: nested at most four pairs deep, with
+/// regular numbers below 10.
+fn day18(rng: &mut Rng, scale: usize) -> String {
+    fn element(rng: &mut Rng, depth: usize) -> String {
+        match depth < 4 && rng.chance(0.5) {
+            true => pair(rng, depth + 1),
+            false => rng.range(0..=9).to_string(),
+        }
+    }
+    fn pair(rng: &mut Rng, depth: usize) -> String {
+        format!("[{},{}]", element(rng, depth), element(rng, depth))
+    }
+    (0..scale).map(|_| pair(rng, 1)).join("\n")
+}
+
+/// `scale` scanners in a chain, each seeing 14 beacons in common with the
+/// one before it and some more of its own, turned every which way.
+fn day19(rng: &mut Rng, scale: usize) -> String {
+    const RANGE: i64 = 1000;
+    let mut positions = vec![[0i64; 3]];
+    for i in 1..scale {
+        let step = [0, 1, 2].map(|_| rng.range(-RANGE..=RANGE));
+        positions.push([0, 1, 2].map(|axis| positions[i - 1][axis] + step[axis]));
+    }
+    let point = |rng: &mut Rng, low: [i64; 3], high: [i64; 3]| {
+        [0, 1, 2].map(|i| rng.range(low[i]..=high[i]))
+    };
+    let mut beacons = HashSet::new();
+    for (i, position) in positions.iter().enumerate() {
+        for _ in 0..rng.range(5..=12) {
+            beacons.insert(point(
+                rng,
+                position.map(|p| p - RANGE),
+                position.map(|p| p + RANGE),
+            ));
+        }
+        if i > 0 {
+            let before = positions[i - 1];
+            let low = [0, 1, 2].map(|axis| before[axis].max(position[axis]) - RANGE);
+            let high = [0, 1, 2].map(|axis| before[axis].min(position[axis]) + RANGE);
+            for _ in 0..14 {
+                beacons.insert(point(rng, low, high));
+            }
+        }
+    }
+
+    positions
+        .iter()
+        .enumerate()
+        .map(|(i, position)| {
+            // A random proper rotation: permute the axes, flip some of them,
+            // and flip one more if that made a mirror image.
+            let mut axes = [0, 1, 2];
+            rng.shuffle(&mut axes);
+            let mut signs = [0, 1, 2].map(|_| if rng.chance(0.5) { 1 } else { -1 });
+            let odd = (axes[0] > axes[1]) ^ (axes[0] > axes[2]) ^ (axes[1] > axes[2]);
+            if odd ^ (signs.iter().product::<i64>() < 0) {
+                signs[0] = -signs[0];
+            }
+            let seen = beacons
+                .iter()
+                .map(|beacon| [0, 1, 2].map(|axis| beacon[axis] - position[axis]))
+                .filter(|relative| relative.iter().all(|d| d.abs() <= RANGE))
+                .map(|relative| {
+                    let turned = [0, 1, 2].map(|axis| signs[axis] * relative[axes[axis]]);
+                    turned.iter().join(",")
+                })
+                .sorted();
+            format!("--- scanner {} ---\n{}", i, seen.format("\n"))
+        })
+        .join("\n\n")
+}
+
+/// A random algorithm and a `scale` by `scale` image. Half of the algorithms
+/// light up the background on odd passes; those always turn it off again.
+fn day20(rng: &mut Rng, scale: usize) -> String {
+    let mut algorithm = (0..512).map(|_| rng.chance(0.5)).collect_vec();
+    if algorithm[0] {
+        algorithm[511] = false;
+    }
+    let pixel = |lit: bool| if lit { '#' } else { '.' };
+    let image = (0..scale)
+        .map(|_| {
+            (0..scale)
+                .map(|_| pixel(rng.chance(0.5)))
+                .collect::<String>()
+        })
+        .join("\n");
+    format!(
+        "{}\n\n{}",
+        algorithm.into_iter().map(pixel).collect::<String>(),
+        image
+    )
+}
+
+/// Two random starting positions; the scale is ignored.
+fn day21(rng: &mut Rng, _scale: usize) -> String {
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}",
+        rng.range(1..=10),
+        rng.range(1..=10)
+    )
+}
+
+/// `scale` reboot steps. The first twentieth stay within the initialization
+/// region, like in the real inputs.
+fn day22(rng: &mut Rng, scale: usize) -> String {
+    let initialization = scale.div_ceil(20);
+    (0..scale)
+        .map(|i| {
+            let (reach, size) = match i < initialization {
+                true => (50, 50),
+                false => (100_000, 50_000),
+            };
+            let mut range = |axis| {
+                let start = rng.range(-reach..=reach - size / 2);
+                let end = (start + rng.range(0..=size)).min(reach);
+                format!("{}={}..{}", axis, start, end)
+            };
+            let cube = [range('x'), range('y'), range('z')].join(",");
+            let state = if i == 0 || rng.chance(0.7) {
+                "on"
+            } else {
+                "off"
+            };
+            format!("{} {}", state, cube)
+        })
+        .join("\n")
+}
+
+/// Four rooms `scale` levels deep (at most four), with the amphipods
+/// shuffled between them.
+fn day23(rng: &mut Rng, scale: usize) -> String {
+    let depth = scale.min(4);
+    let mut amphipods = (0..4 * depth)
+        .map(|i| (b'A' + (i % 4) as u8) as char)
+        .collect_vec();
+    rng.shuffle(&mut amphipods);
+    let levels = amphipods
+        .chunks(4)
+        .enumerate()
+        .map(|(level, room)| {
+            let wall = if level == 0 { "##" } else { "  " };
+            format!("{}#{}#{}", wall, room.iter().join("#"), wall.trim())
+        })
+        .join("\n");
+    format!("#############\n#...........#\n{}\n  #########", levels)
+}
+
+/// A MONAD program with its 14 blocks paired up at random, each pair
+/// accepting some digits. Model numbers always have 14 digits, so there is
+/// nothing to scale.
+fn day24(rng: &mut Rng) -> String {
+    let mut blocks = vec![];
+    let mut open = vec![];
+    for i in 0..day24::DIGITS {
+        let pushes_left = day24::DIGITS / 2 - (i + open.len()) / 2;
+        if !open.is_empty() && (pushes_left == 0 || rng.chance(0.5)) {
+            let offset: i64 = open.pop().unwrap();
+            let check = rng.range(-8..=8) - offset;
+            blocks.push(Block {
+                pop: true,
+                check,
+                offset: rng.range(1..=16),
+            });
+        } else {
+            let offset = rng.range(0..=16);
+            open.push(offset);
+            blocks.push(Block {
+                pop: false,
+                check: rng.range(10..=16),
+                offset,
+            });
+        }
+    }
+    day24::monad(&blocks)
+}
+
+/// A `scale` by `scale` sea floor, about a third of it per herd. One full row
+/// of `>` and one full column of `v` can never move, and nothing can get past
+/// them, so the herds always come to a stop.
+fn day25(rng: &mut Rng, scale: usize) -> String {
+    let (row, column) = (rng.below(scale as u64), rng.below(scale as u64));
+    (0..scale as u64)
+        .map(|y| {
+            (0..scale as u64)
+                .map(|x| match (y == row, x == column) {
+                    (_, true) => 'v',
+                    (true, false) => '>',
+                    _ => *rng.choose(&['>', 'v', '.']),
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+#[cfg(test)]
+pub mod tests {
+    use aoc::runner::{Day, Part};
+
+    use super::input;
+
+    #[test]
+    fn deterministic() {
+        assert_eq!(input(12, 7, 20), input(12, 7, 20));
+        assert_ne!(input(12, 7, 20), input(12, 8, 20));
+        assert_eq!(input(26, 7, 20), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        for day in crate::days() {
+            for (seed, scale) in [(0, 1), (1, 2), (2, 10), (3, 50)] {
+                let text = input(day.day, seed, scale)
+                    .unwrap_or_else(|| panic!("no generator for day{}", day.day));
+                if let Err(err) = day.generate(&text) {
+                    panic!("day{} seed {} scale {}: {}", day.day, seed, scale, err);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sizes() {
+        let scale = 7;
+        let lines = |day| input(day, 3, scale).unwrap().lines().count();
+        for day in [1, 2, 3, 5, 10, 18, 22] {
+            assert_eq!(lines(day), scale, "day{}", day);
+        }
+        for day in [6, 7] {
+            assert_eq!(input(day, 3, scale).unwrap().split(',').count(), scale);
+        }
+        for day in [9, 11, 15, 25] {
+            let text = input(day, 3, scale).unwrap();
+            assert!(text.lines().all(|line| line.len() == scale), "day{}", day);
+            assert_eq!(text.lines().count(), scale, "day{}", day);
+        }
+        assert_eq!(input(4, 3, scale).unwrap().split("\n\n").count(), 1 + scale);
+        assert_eq!(
+            input(19, 3, scale).unwrap().matches("--- scanner").count(),
+            scale
+        );
+        assert_eq!(lines(23), 3 + 4);
+        assert_eq!(input(23, 3, 2).unwrap().lines().count(), 3 + 2);
+        assert_eq!(input(24, 3, scale).unwrap().matches("inp w").count(), 14);
+    }
+
+    /// Days whose solutions take too long in a debug build for the default
+    /// test run.
+    const EXPENSIVE: &[u32] = &[17];
+
+    /// Solves both parts of every day on small generated inputs, failing on
+    /// any error or panic.
+    fn solve(days: impl Iterator<Item = Day>) {
+        for day in days {
+            for (seed, scale) in [(0, 1), (1, 2), (2, 10)] {
+                let text = input(day.day, seed, scale).unwrap();
+                for (part, answer) in day.run(&text, &Part::ALL) {
+                    if let Err(err) = answer {
+                        panic!(
+                            "day{} {} seed {} scale {}: {}",
+                            day.day, part, seed, scale, err
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solvable() {
+        solve(
+            crate::days()
+                .into_iter()
+                .filter(|d| !EXPENSIVE.contains(&d.day)),
+        );
+    }
+
+    #[test]
+    #[ignore] // expensive brute-force :)
+    fn solvable_expensive() {
+        solve(
+            crate::days()
+                .into_iter()
+                .filter(|d| EXPENSIVE.contains(&d.day)),
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! The 2021 solutions. Every `dayN` module has a `parse` function for its
+//! input and `part1` and `part2` functions taking what it returns, along
+//! with a `DayN` type implementing `Solution` for the runner.
+
+#[macro_use]
+extern crate aoc_runner_derive;
+
+use aoc::runner::{Day, Year};
+use aoc_runner_derive::aoc_lib;
+
+pub mod gen;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// All solved days, in order.
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::of::<day1::Day1>(),
+        Day::of::<day2::Day2>(),
+        Day::of::<day3::Day3>(),
+        Day::of::<day4::Day4>(),
+        Day::of::<day5::Day5>(),
+        Day::of::<day6::Day6>(),
+        Day::of::<day7::Day7>(),
+        Day::of::<day8::Day8>(),
+        Day::of::<day9::Day9>(),
+        Day::of::<day10::Day10>(),
+        Day::of::<day11::Day11>(),
+        Day::of::<day12::Day12>(),
+        Day::of::<day13::Day13>(),
+        Day::of::<day14::Day14>(),
+        Day::of::<day15::Day15>(),
+        Day::of::<day16::Day16>(),
+        Day::of::<day17::Day17>(),
+        Day::of::<day18::Day18>(),
+        Day::of::<day19::Day19>(),
+        Day::of::<day20::Day20>(),
+        Day::of::<day21::Day21>(),
+        Day::of::<day22::Day22>(),
+        Day::of::<day23::Day23>(),
+        Day::of::<day24::Day24>(),
+        Day::of::<day25::Day25>(),
+    ]
+}
+
+/// The year these puzzles are from.
+pub const YEAR: u32 = 2021;
+
+/// This crate's puzzles, for the runner.
+pub fn year() -> Year {
+    Year { year: YEAR, days }
+}
+
+aoc_lib! { year = 2021 }
@@ -0,0 +1,88 @@
+//! Runs every solved day on its real input from `input/2021` and compares the
+//! results with the answers recorded in `answers/2021.toml`, both below the
+//! workspace root.
+//!
+//! Parts without a recorded answer (or days without an input) are reported as
+//! missing rather than passed, unless the part has no answer at all. Set
+//! `AOC_REQUIRE_ANSWERS=1` to fail on them.
+
+use std::{env, fs, path::Path};
+
+use aoc::{
+    answers::Answers,
+    runner::{Day, Part},
+    solution::Answer,
+};
+use aoc2021::{days, year};
+
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Days that take too long in a debug build for the default test run.
+const EXPENSIVE: &[u32] = &[17];
+
+fn check(days: impl Iterator<Item = Day>) {
+    let year = year();
+    let answers = Answers::load(Path::new(ROOT).join(year.answers_path())).unwrap();
+    let (mut passed, mut missing, mut failed) = (0, vec![], vec![]);
+
+    for day in days {
+        let path = Path::new(ROOT).join(year.input_path(day.day));
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                missing.push(format!(
+                    "day{}: cannot read {}: {}",
+                    day.day,
+                    path.display(),
+                    err
+                ));
+                continue;
+            }
+        };
+
+        for (part, result) in day.run(&input, &Part::ALL) {
+            match (result, answers.get(day.day, part)) {
+                (Err(err), _) => failed.push(format!("day{} {}: {}", day.day, part, err)),
+                (Ok(Answer::None), None) => {}
+                (Ok(answer), None) => missing.push(format!(
+                    "day{} {}: no recorded answer, got {}",
+                    day.day, part, answer
+                )),
+                (Ok(answer), Some(expected)) if answer.to_string() == expected => passed += 1,
+                (Ok(answer), Some(expected)) => failed.push(format!(
+                    "day{} {}: expected {}, got {}",
+                    day.day, part, expected, answer
+                )),
+            }
+        }
+    }
+
+    println!(
+        "{} passed, {} missing, {} failed",
+        passed,
+        missing.len(),
+        failed.len()
+    );
+    for line in &missing {
+        println!("missing: {}", line);
+    }
+    for line in &failed {
+        println!("FAILED: {}", line);
+    }
+
+    assert!(failed.is_empty(), "{} answers are wrong", failed.len());
+    if env::var_os("AOC_REQUIRE_ANSWERS").is_some() {
+        assert!(missing.is_empty(), "{} answers are missing", missing.len());
+    }
+}
+
+#[test]
+fn real_inputs() {
+    check(days().into_iter().filter(|d| !EXPENSIVE.contains(&d.day)));
+}
+
+#[test]
+#[ignore] // expensive brute-force :)
+fn real_inputs_expensive() {
+    check(days().into_iter().filter(|d| EXPENSIVE.contains(&d.day)));
+}
@@ -0,0 +1,60 @@
+//! Uses the solutions the way another crate would: through the public `dayN`
+//! modules rather than the runner.
+
+use aoc2021::{day1, day15, day16, day22, day4};
+
+#[test]
+fn days() {
+    let depths = day1::parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263").unwrap();
+    assert_eq!((day1::part1(&depths), day1::part2(&depths)), (7, 5));
+
+    let transmission = day16::parse("9c0141080250320f1802104a08").unwrap();
+    assert_eq!(day16::part2(&transmission), 1);
+}
+
+#[test]
+fn building_blocks() {
+    let grid = day15::parse("191\n111").unwrap();
+    assert_eq!(
+        day15::dijkstra(&grid, (0, 0), (0, 2)),
+        Some((4, vec![(0, 0), (1, 0), (1, 1), (1, 2), (0, 2)]))
+    );
+
+    let packet = day16::decode("C200B40A82").unwrap();
+    assert!(matches!(
+        packet,
+        day16::Packet::Operator {
+            operator: day16::Operator::Sum,
+            ..
+        }
+    ));
+    assert_eq!(day16::eval(&packet), 3);
+
+    let steps = day22::parse("on x=0..9,y=0..9,z=0..9\non x=5..14,y=5..14,z=5..14").unwrap();
+    let overlap = steps[0].cube.intersection(&steps[1].cube).unwrap();
+    assert_eq!(overlap.size(), 125);
+
+    let (numbers, mut boards) = day4::parse(
+        "1,2,3,4,5\n\n1 2 3 4 5\n6 7 8 9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25",
+    )
+    .unwrap();
+    for n in numbers {
+        boards[0].process_number(n);
+    }
+    assert!(boards[0].has_won());
+    assert_eq!(boards[0].calc_score(5), (6..=25).sum::<i32>() * 5);
+
+    let mut board = day4::Board::new([
+        [1, 2, 3, 4, 5],
+        [6, 7, 8, 9, 10],
+        [11, 12, 13, 14, 15],
+        [16, 17, 18, 19, 20],
+        [21, 22, 23, 24, 25],
+    ]);
+    for n in [3, 8, 13, 18] {
+        board.process_number(n);
+    }
+    assert!(!board.has_won());
+    board.process_number(23);
+    assert!(board.has_won());
+}
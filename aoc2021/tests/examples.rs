@@ -0,0 +1,44 @@
+//! Runs every solved day on the examples from its puzzle text, as registered
+//! through `Solution::examples`, and compares the results with the answers the
+//! text gives.
+
+use aoc::runner::{Day, Part};
+use aoc2021::days;
+
+/// Days that take too long in a debug build for the default test run, even
+/// on their examples.
+const EXPENSIVE: &[u32] = &[17];
+
+fn check(days: impl Iterator<Item = Day>) {
+    let mut failed = vec![];
+    for day in days {
+        for run in day.run_examples(&Part::ALL) {
+            if !run.passed() {
+                failed.push(format!(
+                    "day{} example {} {}: expected {:?}, got {:?}",
+                    day.day, run.example, run.part, run.expected, run.found
+                ));
+            }
+        }
+    }
+
+    for line in &failed {
+        println!("FAILED: {}", line);
+    }
+    assert!(
+        failed.is_empty(),
+        "{} example answers are wrong",
+        failed.len()
+    );
+}
+
+#[test]
+fn examples() {
+    check(days().into_iter().filter(|d| !EXPENSIVE.contains(&d.day)));
+}
+
+#[test]
+#[ignore] // expensive brute-force :)
+fn examples_expensive() {
+    check(days().into_iter().filter(|d| EXPENSIVE.contains(&d.day)));
+}
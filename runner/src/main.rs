@@ -0,0 +1,750 @@
+mod scaffold;
+
+use std::{
+    env, fs,
+    io::{self, Read},
+    ops::RangeInclusive,
+    process,
+    time::Duration,
+};
+
+use aoc::{
+    bench::{self, Format},
+    parallel::{self, Status, Summary, Task},
+    render,
+    runner::{self, Day, Part, Year},
+};
+
+const USAGE: &str = "\
+Usage: aoc [DAYS] [--year <YEAR>] [--part <1|2>] [--input <FILE|-> | --examples]
+           [--bench <RUNS> [OPTIONS] | --render <FILE> [OPTIONS] | --report |
+            --jobs <THREADS> [--timeout <SECS>]]
+       aoc new <DAY> [--year <YEAR>]
+
+  DAYS              `all` (default), a single day like `5` or a range like `3..7`
+  -y, --year        the year to run (default: the latest one)
+  -p, --part        only run the given part
+  -i, --input       read the input from FILE, or from stdin if FILE is `-`;
+                    only valid for a single day (default: input/YEAR/dayN.txt)
+  -e, --examples    run on the examples from the puzzle text instead and print
+                    the answers next to the expected ones
+  -b, --bench       time the generator and each part over RUNS runs instead of
+                    printing the answers
+  -r, --render      draw the day's final state into FILE (.ppm or .svg) instead
+                    of printing the answers; only valid for a single day
+      --report      print the day's analysis of its input as JSON instead of
+                    the answers; only valid for a single day
+  -j, --jobs        run the days on THREADS threads at once and print a summary
+                    table; a panicking or slow day only fails itself
+
+Benchmark options:
+  --format          `csv` (default) or `json`
+  --baseline        compare with timings saved in FILE and fail if a median
+                    got slower by more than the threshold
+  --threshold       allowed slowdown in percent (default: 10)
+
+Render options:
+  --step            draw the state after N steps instead of the final one
+  --cell            size of a grid cell in pixels (default: 4)
+
+Parallel options:
+  --timeout         give up on a day after SECS seconds (default: 60)
+
+`aoc new` creates the module for DAY from the year's dayX.rs template and
+registers it, along with an empty input file and a blank answers entry.";
+
+#[derive(Debug, PartialEq)]
+enum Input {
+    Default,
+    File(String),
+    Stdin,
+}
+
+#[derive(Debug, PartialEq)]
+struct Bench {
+    runs: usize,
+    format: Format,
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+#[derive(Debug, PartialEq)]
+struct Render {
+    path: String,
+    step: Option<usize>,
+    cell: usize,
+}
+
+#[derive(Debug, PartialEq)]
+struct Parallel {
+    threads: usize,
+    timeout: Duration,
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    year: Option<u32>,
+    days: RangeInclusive<u32>,
+    parts: Vec<Part>,
+    input: Input,
+    examples: bool,
+    bench: Option<Bench>,
+    render: Option<Render>,
+    report: bool,
+    parallel: Option<Parallel>,
+}
+
+impl Options {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+        let mut year = None;
+        let mut days = None;
+        let mut parts = Part::ALL.to_vec();
+        let mut input = Input::Default;
+        let mut examples = false;
+        let mut runs = None;
+        let mut format = Format::Csv;
+        let mut baseline = None;
+        let mut threshold = 10.0;
+        let mut render = None;
+        let mut step = None;
+        let mut cell = None;
+        let mut report = false;
+        let mut threads = None;
+        let mut timeout = None;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+            match arg.as_str() {
+                "-y" | "--year" => {
+                    let value = value()?;
+                    year = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("invalid year `{}`", value))?,
+                    );
+                }
+                "-p" | "--part" => parts = vec![value()?.parse()?],
+                "-i" | "--input" => {
+                    input = match value()?.as_str() {
+                        "-" => Input::Stdin,
+                        path => Input::File(path.to_string()),
+                    };
+                }
+                "-e" | "--examples" => examples = true,
+                "-b" | "--bench" => match value()?.parse() {
+                    Ok(n) if n > 0 => runs = Some(n),
+                    _ => return Err("--bench expects a positive number of runs".to_string()),
+                },
+                "--format" => format = value()?.parse()?,
+                "--baseline" => baseline = Some(value()?),
+                "--threshold" => match value()?.parse() {
+                    Ok(pct) if pct >= 0.0 => threshold = pct,
+                    _ => return Err("--threshold expects a percentage".to_string()),
+                },
+                "-r" | "--render" => render = Some(value()?),
+                "--step" => match value()?.parse() {
+                    Ok(n) => step = Some(n),
+                    _ => return Err("--step expects a number of steps".to_string()),
+                },
+                "--cell" => match value()?.parse() {
+                    Ok(n) if n > 0 => cell = Some(n),
+                    _ => return Err("--cell expects a positive number of pixels".to_string()),
+                },
+                "--report" => report = true,
+                "-j" | "--jobs" => match value()?.parse() {
+                    Ok(n) if n > 0 => threads = Some(n),
+                    _ => return Err("--jobs expects a positive number of threads".to_string()),
+                },
+                "--timeout" => match value()?.parse() {
+                    Ok(secs) if secs > 0.0 => timeout = Some(Duration::from_secs_f64(secs)),
+                    _ => return Err("--timeout expects a positive number of seconds".to_string()),
+                },
+                _ if days.is_none() && !arg.starts_with('-') => days = Some(parse_days(&arg)?),
+                _ => return Err(format!("unexpected argument `{}`", arg)),
+            }
+        }
+
+        let days = days.unwrap_or(1..=25);
+        if input != Input::Default && days.start() != days.end() {
+            return Err("--input can only be used with a single day".to_string());
+        }
+        if examples && input != Input::Default {
+            return Err("--examples cannot be combined with --input".to_string());
+        }
+        if examples && (runs.is_some() || render.is_some()) {
+            return Err("--examples cannot be combined with --bench or --render".to_string());
+        }
+
+        let bench = match runs {
+            Some(runs) => Some(Bench {
+                runs,
+                format,
+                baseline,
+                threshold,
+            }),
+            None if baseline.is_some() => {
+                return Err("--baseline can only be used with --bench".to_string())
+            }
+            None => None,
+        };
+
+        let render = match render {
+            Some(_) if bench.is_some() => {
+                return Err("--render cannot be combined with --bench".to_string())
+            }
+            Some(_) if days.start() != days.end() => {
+                return Err("--render can only be used with a single day".to_string())
+            }
+            Some(path) => Some(Render {
+                path,
+                step,
+                cell: cell.unwrap_or(4),
+            }),
+            None if step.is_some() || cell.is_some() => {
+                return Err("--step and --cell can only be used with --render".to_string())
+            }
+            None => None,
+        };
+
+        if report && (examples || bench.is_some() || render.is_some()) {
+            return Err(
+                "--report cannot be combined with --examples, --bench or --render".to_string(),
+            );
+        }
+        if report && days.start() != days.end() {
+            return Err("--report can only be used with a single day".to_string());
+        }
+
+        let parallel = match threads {
+            Some(_) if examples || bench.is_some() || render.is_some() || report => {
+                return Err(
+                    "--jobs cannot be combined with --examples, --bench, --render or --report"
+                        .to_string(),
+                )
+            }
+            Some(threads) => Some(Parallel {
+                threads,
+                timeout: timeout.unwrap_or(Duration::from_secs(60)),
+            }),
+            None if timeout.is_some() => {
+                return Err("--timeout can only be used with --jobs".to_string())
+            }
+            None => None,
+        };
+
+        Ok(Options {
+            year,
+            days,
+            parts,
+            input,
+            examples,
+            bench,
+            render,
+            report,
+            parallel,
+        })
+    }
+}
+
+/// The arguments of `aoc new`.
+#[derive(Debug, PartialEq)]
+struct NewDay {
+    year: Option<u32>,
+    day: u32,
+}
+
+impl NewDay {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<NewDay, String> {
+        let mut year = None;
+        let mut day = None;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-y" | "--year" => {
+                    let value = args.next().ok_or(format!("missing value for {}", arg))?;
+                    year = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("invalid year `{}`", value))?,
+                    );
+                }
+                _ if day.is_none() && !arg.starts_with('-') => {
+                    let days = parse_days(&arg)?;
+                    if days.start() != days.end() {
+                        return Err("`new` expects a single day".to_string());
+                    }
+                    day = Some(*days.start());
+                }
+                _ => return Err(format!("unexpected argument `{}`", arg)),
+            }
+        }
+
+        let day = day.ok_or("`new` expects a day")?;
+        Ok(NewDay { year, day })
+    }
+}
+
+fn parse_days(arg: &str) -> Result<RangeInclusive<u32>, String> {
+    let parse_day = |s: &str| match s.parse::<u32>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day `{}`, expected 1 to 25", s)),
+    };
+
+    if arg == "all" {
+        Ok(1..=25)
+    } else if let Some((first, last)) = arg.split_once("..") {
+        Ok(parse_day(first)?..=parse_day(last)?)
+    } else {
+        let day = parse_day(arg)?;
+        Ok(day..=day)
+    }
+}
+
+/// Every year the runner knows about. A new year's crate only needs to be
+/// added here (and to the dependencies).
+fn years() -> Vec<Year> {
+    vec![aoc2021::year()]
+}
+
+fn read_input(input: &Input, year: &Year, day: u32) -> io::Result<String> {
+    match input {
+        Input::Default => fs::read_to_string(year.input_path(day)),
+        Input::File(path) => fs::read_to_string(path),
+        Input::Stdin => {
+            let mut buffer = String::new();
+            io::stdin().read_to_string(&mut buffer)?;
+            Ok(buffer)
+        }
+    }
+}
+
+/// The year asked for, or the latest one. Exits if there is no such year.
+fn find_year(years: &[Year], wanted: Option<u32>) -> &Year {
+    match wanted {
+        Some(wanted) => years.iter().find(|y| y.year == wanted).unwrap_or_else(|| {
+            let known = years.iter().map(|y| y.year.to_string()).collect::<Vec<_>>();
+            eprintln!(
+                "error: no solutions for {}, available years: {}",
+                wanted,
+                known.join(", ")
+            );
+            process::exit(2);
+        }),
+        None => years.iter().max_by_key(|y| y.year).expect("no years"),
+    }
+}
+
+fn usage_error(err: String) -> ! {
+    eprintln!("error: {}\n\n{}", err, USAGE);
+    process::exit(2);
+}
+
+fn main() {
+    let mut args = env::args().skip(1).peekable();
+    if args.peek().map(String::as_str) == Some("new") {
+        let new = NewDay::parse(args.skip(1)).unwrap_or_else(|err| usage_error(err));
+        if run_new(&new) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let options = Options::parse(args).unwrap_or_else(|err| usage_error(err));
+    runner::quiet_panics();
+    let years = years();
+    let year = find_year(&years, options.year);
+
+    let days = (year.days)()
+        .into_iter()
+        .filter(|d| options.days.contains(&d.day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        eprintln!(
+            "error: no solved day of {} in {:?}",
+            year.year, options.days
+        );
+        process::exit(2);
+    }
+
+    let failed = match (&options.bench, &options.render) {
+        _ if options.examples => run_examples(&options, &days),
+        _ if options.parallel.is_some() => run_parallel(&options, year, days),
+        _ if options.report => run_report(&options, year, &days[0]),
+        (Some(bench), _) => run_bench(&options, bench, year, &days),
+        (_, Some(render)) => run_render(&options, render, year, &days[0]),
+        _ => run_answers(&options, year, &days),
+    };
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Prints the answers of every day, returning whether any of them failed.
+fn run_answers(options: &Options, year: &Year, days: &[Day]) -> bool {
+    let mut failed = false;
+    for day in days {
+        let input = match read_input(&options.input, year, day.day) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("day{}: cannot read input: {}", day.day, err);
+                failed = true;
+                continue;
+            }
+        };
+
+        for (part, result) in day.run(&input, &options.parts) {
+            match result {
+                Ok(answer) => println!("day{} {}: {}", day.day, part, answer),
+                Err(err) => {
+                    eprintln!("day{} {} failed: {}", day.day, part, err);
+                    failed = true;
+                }
+            }
+        }
+    }
+    failed
+}
+
+/// Scaffolds a new day, returning whether that failed.
+fn run_new(new: &NewDay) -> bool {
+    let years = years();
+    let year = find_year(&years, new.year);
+    match scaffold::new_day(year, new.day) {
+        Ok(changed) => {
+            for path in changed {
+                println!("wrote {}", path.display());
+            }
+            false
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            true
+        }
+    }
+}
+
+/// Runs the days on a thread pool and prints a summary table, returning
+/// whether any of them failed or timed out.
+fn run_parallel(options: &Options, year: &Year, days: Vec<Day>) -> bool {
+    let parallel = options.parallel.as_ref().unwrap();
+    let tasks = days
+        .into_iter()
+        .map(|day| Task {
+            input: read_input(&options.input, year, day.day)
+                .map_err(|err| format!("cannot read input: {}", err)),
+            day,
+        })
+        .collect();
+
+    let outcomes = parallel::run_all(tasks, &options.parts, parallel.threads, parallel.timeout);
+    print!("{}", Summary(&outcomes));
+    outcomes
+        .iter()
+        .any(|outcome| !matches!(outcome.status, Status::Solved(_)))
+}
+
+/// Prints the answers of every day on its examples next to the expected ones,
+/// returning whether any of them failed or differed.
+fn run_examples(options: &Options, days: &[Day]) -> bool {
+    let mut failed = false;
+    for day in days {
+        let runs = day.run_examples(&options.parts);
+        if runs.is_empty() {
+            eprintln!("day{}: no examples", day.day);
+        }
+
+        for run in runs {
+            let name = format!("day{} example {} {}", day.day, run.example, run.part);
+            match (&run.found, &run.expected) {
+                (Err(err), _) => eprintln!("{} failed: {}", name, err),
+                (Ok(found), None) => println!("{}: {} (no expected answer)", name, found),
+                (Ok(found), Some(expected)) if run.passed() => {
+                    println!("{}: {} (expected {})", name, found, expected)
+                }
+                (Ok(found), Some(expected)) => {
+                    println!("{}: {} (expected {}) MISMATCH", name, found, expected)
+                }
+            }
+            failed |= !run.passed();
+        }
+    }
+    failed
+}
+
+/// Prints the timings of every day, returning whether any of them failed or
+/// regressed against the baseline.
+fn run_bench(options: &Options, bench: &Bench, year: &Year, days: &[Day]) -> bool {
+    let baseline = match &bench.baseline {
+        Some(path) => match fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|saved| bench::read(&saved))
+        {
+            Ok(baseline) => Some(baseline),
+            Err(err) => {
+                eprintln!("error: cannot read baseline {}: {}", path, err);
+                return true;
+            }
+        },
+        None => None,
+    };
+
+    let mut failed = false;
+    let mut measurements = vec![];
+    for day in days {
+        let result = read_input(&options.input, year, day.day)
+            .map_err(|err| format!("cannot read input: {}", err))
+            .and_then(|input| bench::measure(day, &input, &options.parts, bench.runs));
+        match result {
+            Ok(day_measurements) => measurements.extend(day_measurements),
+            Err(err) => {
+                eprintln!("day{}: {}", day.day, err);
+                failed = true;
+            }
+        }
+    }
+    println!("{}", bench::write(&measurements, bench.format));
+
+    for regression in bench::compare(
+        &measurements,
+        baseline.as_deref().unwrap_or(&[]),
+        bench.threshold,
+    ) {
+        eprintln!("regression: {}", regression);
+        failed = true;
+    }
+    failed
+}
+
+/// Draws the day's state into an image file, returning whether that failed.
+fn run_render(options: &Options, render: &Render, year: &Year, day: &Day) -> bool {
+    let result = read_input(&options.input, year, day.day)
+        .map_err(|err| format!("cannot read input: {}", err))
+        .and_then(|input| day.image(&input, render.step))
+        .and_then(|image| {
+            render::save(&image, &render.path, render.cell).map_err(|err| err.to_string())
+        });
+    match result {
+        Ok(()) => {
+            println!("day{}: wrote {}", day.day, render.path);
+            false
+        }
+        Err(err) => {
+            eprintln!("day{}: {}", day.day, err);
+            true
+        }
+    }
+}
+
+/// Prints the day's analysis of its input, returning whether that failed.
+fn run_report(options: &Options, year: &Year, day: &Day) -> bool {
+    let result = read_input(&options.input, year, day.day)
+        .map_err(|err| format!("cannot read input: {}", err))
+        .and_then(|input| day.analysis(&input));
+    match result {
+        Ok(report) => {
+            println!("{:#}", report);
+            false
+        }
+        Err(err) => {
+            eprintln!("day{}: {}", day.day, err);
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::time::Duration;
+
+    use super::{Bench, Format, Input, NewDay, Options, Parallel, Part, Render};
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn defaults() {
+        assert_eq!(
+            parse(&[]),
+            Ok(Options {
+                year: None,
+                days: 1..=25,
+                parts: vec![Part::One, Part::Two],
+                input: Input::Default,
+                examples: false,
+                bench: None,
+                render: None,
+                report: false,
+                parallel: None,
+            })
+        );
+        assert_eq!(parse(&["all"]).unwrap().days, 1..=25);
+    }
+
+    #[test]
+    fn day_selection() {
+        assert_eq!(parse(&["5"]).unwrap().days, 5..=5);
+        assert_eq!(parse(&["3..7"]).unwrap().days, 3..=7);
+        assert!(parse(&["0"]).is_err());
+        assert!(parse(&["26"]).is_err());
+        assert!(parse(&["3..x"]).is_err());
+
+        assert_eq!(parse(&["-y", "2021", "5"]).unwrap().year, Some(2021));
+        assert_eq!(parse(&["--year", "2021"]).unwrap().days, 1..=25);
+        assert!(parse(&["--year", "twenty"]).is_err());
+    }
+
+    #[test]
+    fn part_and_input() {
+        let options = parse(&["5", "--part", "2", "-i", "-"]).unwrap();
+        assert_eq!(options.parts, vec![Part::Two]);
+        assert_eq!(options.input, Input::Stdin);
+
+        let options = parse(&["-i", "example.txt", "12"]).unwrap();
+        assert_eq!(options.input, Input::File("example.txt".to_string()));
+        assert_eq!(options.days, 12..=12);
+
+        assert!(parse(&["3..7", "--input", "example.txt"]).is_err());
+        assert!(parse(&["5", "--part"]).is_err());
+        assert!(parse(&["5", "--bogus"]).is_err());
+    }
+
+    #[test]
+    fn examples() {
+        let options = parse(&["12", "--examples", "-p", "2"]).unwrap();
+        assert!(options.examples);
+        assert_eq!(options.parts, vec![Part::Two]);
+        assert!(parse(&["-e"]).unwrap().examples);
+
+        assert!(parse(&["12", "-e", "--input", "example.txt"]).is_err());
+        assert!(parse(&["-e", "--bench", "3"]).is_err());
+        assert!(parse(&["5", "-e", "--render", "a.svg"]).is_err());
+    }
+
+    #[test]
+    fn parallel() {
+        assert_eq!(
+            parse(&["--jobs", "4"]).unwrap().parallel,
+            Some(Parallel {
+                threads: 4,
+                timeout: Duration::from_secs(60),
+            })
+        );
+        assert_eq!(
+            parse(&["3..7", "-j", "2", "--timeout", "0.5"])
+                .unwrap()
+                .parallel,
+            Some(Parallel {
+                threads: 2,
+                timeout: Duration::from_millis(500),
+            })
+        );
+
+        assert!(parse(&["-j", "0"]).is_err());
+        assert!(parse(&["-j", "2", "--timeout", "0"]).is_err());
+        assert!(parse(&["--timeout", "5"]).is_err());
+        assert!(parse(&["-j", "2", "--bench", "3"]).is_err());
+        assert!(parse(&["-j", "2", "--examples"]).is_err());
+    }
+
+    #[test]
+    fn new_day() {
+        let parse = |args: &[&str]| NewDay::parse(args.iter().map(|a| a.to_string()));
+        assert_eq!(
+            parse(&["12"]),
+            Ok(NewDay {
+                year: None,
+                day: 12
+            })
+        );
+        assert_eq!(
+            parse(&["-y", "2022", "1"]),
+            Ok(NewDay {
+                year: Some(2022),
+                day: 1
+            })
+        );
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["3..4"]).is_err());
+        assert!(parse(&["3", "4"]).is_err());
+        assert!(parse(&["26"]).is_err());
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(
+            parse(&["--bench", "10"]).unwrap().bench,
+            Some(Bench {
+                runs: 10,
+                format: Format::Csv,
+                baseline: None,
+                threshold: 10.0,
+            })
+        );
+
+        let options = parse(&[
+            "15",
+            "-b",
+            "3",
+            "--format",
+            "json",
+            "--baseline",
+            "before.json",
+            "--threshold",
+            "25",
+        ])
+        .unwrap();
+        assert_eq!(
+            options.bench,
+            Some(Bench {
+                runs: 3,
+                format: Format::Json,
+                baseline: Some("before.json".to_string()),
+                threshold: 25.0,
+            })
+        );
+
+        assert!(parse(&["--bench", "0"]).is_err());
+        assert!(parse(&["--baseline", "before.csv"]).is_err());
+        assert!(parse(&["-b", "5", "--format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn render() {
+        assert_eq!(
+            parse(&["5", "--render", "vents.svg"]).unwrap().render,
+            Some(Render {
+                path: "vents.svg".to_string(),
+                step: None,
+                cell: 4,
+            })
+        );
+        assert_eq!(
+            parse(&["-r", "herds.ppm", "25", "--step", "10", "--cell", "1"])
+                .unwrap()
+                .render,
+            Some(Render {
+                path: "herds.ppm".to_string(),
+                step: Some(10),
+                cell: 1,
+            })
+        );
+
+        assert!(parse(&["--render", "all.svg"]).is_err());
+        assert!(parse(&["5", "--render", "a.svg", "--bench", "3"]).is_err());
+        assert!(parse(&["5", "--step", "3"]).is_err());
+        assert!(parse(&["5", "--render", "a.svg", "--cell", "0"]).is_err());
+    }
+
+    #[test]
+    fn report() {
+        assert!(parse(&["1", "--report", "-i", "-"]).unwrap().report);
+        assert!(parse(&["--report"]).is_err());
+        assert!(parse(&["1", "--report", "-e"]).is_err());
+        assert!(parse(&["1", "--report", "--render", "a.svg"]).is_err());
+        assert!(parse(&["1", "--report", "--jobs", "2"]).is_err());
+    }
+}
@@ -0,0 +1,262 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use aoc::runner::Year;
+
+/// Where a year's crate keeps its day modules, relative to the workspace
+/// root.
+fn source_dir(year: &Year) -> PathBuf {
+    format!("aoc{}/src", year.year).into()
+}
+
+/// Sets up a new day: its module from the `dayX.rs` template, registered in
+/// the crate's `lib.rs`, an empty input file and a blank entry in the answers
+/// manifest. Returns the paths that were created or changed.
+pub fn new_day(year: &Year, day: u32) -> Result<Vec<PathBuf>, String> {
+    let dir = source_dir(year);
+    let module = dir.join(format!("day{}.rs", day));
+    if module.exists() {
+        return Err(format!("{} already exists", module.display()));
+    }
+
+    let template = read(&dir.join("dayX.rs"))?;
+    let lib_path = dir.join("lib.rs");
+    let lib = register(&read(&lib_path)?, day)?;
+    let answers_path = year.answers_path();
+    let answers = add_answers(&read(&answers_path)?, day)?;
+
+    write(&module, &instantiate(&template, day))?;
+    write(&lib_path, &lib)?;
+    write(&answers_path, &answers)?;
+    let mut changed = vec![module, lib_path, answers_path];
+
+    // A real input may already be there, which must not be overwritten.
+    let input = year.input_path(day);
+    if let Some(parent) = input.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("cannot create {}: {}", parent.display(), err))?;
+    }
+    match OpenOptions::new().write(true).create_new(true).open(&input) {
+        Ok(_) => changed.push(input),
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => {}
+        Err(err) => return Err(format!("cannot create {}: {}", input.display(), err)),
+    }
+
+    Ok(changed)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("cannot read {}: {}", path.display(), err))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::File::create(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|err| format!("cannot write {}: {}", path.display(), err))
+}
+
+/// The template with its placeholders for `day` filled in: the identifier
+/// `X` becomes the number, and `dayX` and `DayX` inside identifiers become
+/// `dayN` and `DayN`.
+pub fn instantiate(template: &str, day: u32) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(is_ident) {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest.find(|c| !is_ident(c)).unwrap_or(rest.len());
+        let ident = &rest[..end];
+        if ident == "X" {
+            result.push_str(&day.to_string());
+        } else {
+            result.push_str(
+                &ident
+                    .replace("dayX", &format!("day{}", day))
+                    .replace("DayX", &format!("Day{}", day)),
+            );
+        }
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// `lib.rs` with `pub mod dayN;` added to the module list, which is sorted
+/// by name, and the day added to `days()`, which is sorted by number.
+pub fn register(lib: &str, day: u32) -> Result<String, String> {
+    let module = format!("pub mod day{};", day);
+    let entry = format!("Day::of::<day{}::Day{}>(),", day, day);
+    if lib.lines().any(|line| line.trim() == module) {
+        return Err(format!("day{} is already registered", day));
+    }
+
+    let mut lines = lib.lines().map(str::to_string).collect::<Vec<_>>();
+    let name = format!("day{}", day);
+    let modules = positions(&lines, |line| line.starts_with("pub mod day"));
+    let at = modules
+        .iter()
+        .find(|&&i| lines[i]["pub mod ".len()..].trim_end_matches(';') > name.as_str())
+        .copied()
+        .or_else(|| modules.last().map(|&i| i + 1))
+        .ok_or("no `pub mod dayN;` lines in lib.rs")?;
+    lines.insert(at, module);
+
+    let entries = positions(&lines, |line| line.trim().starts_with("Day::of::<day"));
+    let number = |line: &str| {
+        let line = line.trim().strip_prefix("Day::of::<day")?;
+        line[..line.find(':')?].parse::<u32>().ok()
+    };
+    let at = entries
+        .iter()
+        .find(|&&i| number(&lines[i]).is_some_and(|n| n > day))
+        .copied()
+        .or_else(|| entries.last().map(|&i| i + 1))
+        .ok_or("no `Day::of` entries in lib.rs")?;
+    let first = &lines[entries[0]];
+    let indent = first[..first.len() - first.trim_start().len()].to_string();
+    lines.insert(at, indent + &entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn positions(lines: &[String], wanted: impl Fn(&str) -> bool) -> Vec<usize> {
+    (0..lines.len()).filter(|&i| wanted(&lines[i])).collect()
+}
+
+/// The answers manifest with an empty `[dayN]` table added in order of the
+/// days.
+pub fn add_answers(manifest: &str, day: u32) -> Result<String, String> {
+    let table = |line: &str| {
+        line.trim()
+            .strip_prefix("[day")
+            .and_then(|rest| rest.strip_suffix(']'))
+            .and_then(|number| number.parse::<u32>().ok())
+    };
+    if manifest.lines().any(|line| table(line) == Some(day)) {
+        return Err(format!("the answers already have a [day{}] table", day));
+    }
+
+    let mut lines = manifest.lines().collect::<Vec<_>>();
+    let at = lines
+        .iter()
+        .position(|line| table(line).is_some_and(|n| n > day))
+        .unwrap_or(lines.len());
+    let new_table = format!("[day{}]", day);
+    let mut insert = vec![new_table.as_str(), ""];
+    if at == lines.len() {
+        insert.rotate_left(1);
+    }
+    lines.splice(at..at, insert);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{add_answers, instantiate, register};
+
+    static LIB: &str = "\
+pub mod day1;
+pub mod day10;
+pub mod day2;
+
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::of::<day1::Day1>(),
+        Day::of::<day2::Day2>(),
+        Day::of::<day10::Day10>(),
+    ]
+}
+";
+
+    #[test]
+    fn template() {
+        let template = "\
+#[aoc_generator(dayX)]
+pub fn parse(input: &str) -> Vec<i32> {
+    lines(X, input)
+}
+
+pub struct DayX;
+
+impl Solution for DayX {
+    const DAY: u32 = X;
+}
+
+fn dayX_part1() {
+    let Xs = MAX;
+}
+";
+        assert_eq!(
+            instantiate(template, 7),
+            "\
+#[aoc_generator(day7)]
+pub fn parse(input: &str) -> Vec<i32> {
+    lines(7, input)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+}
+
+fn day7_part1() {
+    let Xs = MAX;
+}
+"
+        );
+    }
+
+    #[test]
+    fn registration() {
+        let lib = register(LIB, 3).unwrap();
+        assert_eq!(
+            lib,
+            "\
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::of::<day1::Day1>(),
+        Day::of::<day2::Day2>(),
+        Day::of::<day3::Day3>(),
+        Day::of::<day10::Day10>(),
+    ]
+}
+"
+        );
+
+        let lib = register(LIB, 11).unwrap();
+        assert!(lib.contains("pub mod day10;\npub mod day11;\npub mod day2;"));
+        assert!(lib.contains("Day10>(),\n        Day::of::<day11::Day11>(),\n    ]"));
+
+        let lib = register(LIB, 0).unwrap();
+        assert!(lib.starts_with("pub mod day0;\npub mod day1;"));
+
+        assert!(register(LIB, 10).is_err());
+    }
+
+    #[test]
+    fn answers() {
+        let manifest = "# answers\n\n[day1]\npart1 = 7\n\n[day3]\npart1 = 9\n";
+        assert_eq!(
+            add_answers(manifest, 2).unwrap(),
+            "# answers\n\n[day1]\npart1 = 7\n\n[day2]\n\n[day3]\npart1 = 9\n"
+        );
+        assert_eq!(
+            add_answers(manifest, 4).unwrap(),
+            "# answers\n\n[day1]\npart1 = 7\n\n[day3]\npart1 = 9\n\n[day4]\n"
+        );
+        assert!(add_answers(manifest, 3).is_err());
+    }
+}
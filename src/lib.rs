@@ -1,27 +0,0 @@
-#[macro_use]
-extern crate aoc_runner_derive;
-
-use aoc_runner_derive::aoc_lib;
-
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day2;
-mod day21;
-mod day22;
-mod day25;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-
-aoc_lib! { year = 2021 }